@@ -67,14 +67,16 @@ impl Beam for AdminPage {
     }
 }
 
-fn main() {
-    let ui = UI! {
+fn page() -> UI {
+    UI! {
         <Layout title="admin page">  // title: "admin page".into()
             <AdminPage />  // children: (AdminPage {}).render()
         </Layout>
-    };
+    }
+}
 
-    let html = uibeam::shoot(ui);
+fn main() {
+    let html = uibeam::shoot(page());
 
     println!("{html}");
 }
@@ -82,12 +84,5 @@ fn main() {
 #[cfg(test)]
 #[test]
 fn test_html() {
-    let mut output = String::from_utf8(
-        std::process::Command::new("cargo").arg("run").output().unwrap().stdout
-    ).unwrap();
-
-    // Remove the last newline character of `println!` output
-    output.pop();
-
-    assert_eq!(output, include_str!("../expected.html"));
+    uibeam::testing::assert_snapshot!(page());
 }
@@ -80,18 +80,59 @@ impl Beam for Counter {
 #[cfg(test)]
 #[test]
 fn test_html() {
-    let html = uibeam::shoot(UI! {
+    let ui = UI! {
         <Layout title="Test Counter">
             <Counter initial_count={5} />
         </Layout>
+    };
+    uibeam::testing::assert_snapshot!(ui);
+}
+
+#[cfg(test)]
+#[test]
+fn test_island() {
+    use uibeam::testing::Document;
+
+    let document = Document::render(UI! {
+        <Counter initial_count={-1} />
     });
 
-    let expected_html = include_str!("../expected.pretty.html")
-        .split('\n')
-        .map(str::trim)
-        .collect::<Vec<_>>()
-        .join("")
-        .replace("\",\"", "\", \""); // handling importmap format
+    let island = document.find("[data-uibeam-hydrater]").unwrap();
+    assert_eq!(island.attribute("data-uibeam-props"), Some(r#"{"initial_count":-1}"#));
+    assert_eq!(island.find("p").unwrap().text(), "Count: -1");
+    assert_eq!(
+        island.select("div > button").iter().map(|b| b.text()).collect::<Vec<_>>(),
+        ["-", "+"]
+    );
+}
+
+#[cfg(test)]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct WideCounter {
+    initial_count: i32,
+}
+#[cfg(test)]
+#[uibeam::client(island, hydrate = "media (min-width: 800px)")]
+impl Beam for WideCounter {
+    fn render(self) -> UI {
+        UI! {
+            <p>"Count: "{self.initial_count}</p>
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_island_strategy() {
+    use uibeam::testing::Document;
+
+    let document = Document::render(UI! {
+        <Counter initial_count={0} />
+        <WideCounter initial_count={0} />
+    });
 
-    assert_eq!(html, expected_html);
+    let islands = document.select("[data-uibeam-hydrater]");
+    assert_eq!(islands[0].attribute("data-uibeam-strategy"), None);
+    assert_eq!(islands[1].attribute("data-uibeam-strategy"), Some("media"));
+    assert_eq!(islands[1].attribute("data-uibeam-media"), Some("(min-width: 800px)"));
 }
@@ -32,8 +32,8 @@ impl Beam for Hello {
     }
 }
 
-fn main() {
-    let html = uibeam::shoot(UI! {
+fn page() -> UI {
+    UI! {
         <body>
             <h1>"UIBeam example"</h1>
             <custom-element id="example" />
@@ -51,7 +51,11 @@ fn main() {
                 <p>"[message] this is a test message"</p>
             </Hello>
         </body>
-    });
+    }
+}
+
+fn main() {
+    let html = uibeam::shoot(page());
 
     println!("{html}");
 }
@@ -59,12 +63,5 @@ fn main() {
 #[cfg(test)]
 #[test]
 fn test_html() {
-    let mut output = String::from_utf8(
-        std::process::Command::new("cargo").arg("run").output().unwrap().stdout
-    ).unwrap();
-
-    // Remove the last newline character of `println!` output
-    output.pop();
-
-    assert_eq!(output, include_str!("../expected.html"));
+    uibeam::testing::assert_snapshot!(page());
 }
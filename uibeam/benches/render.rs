@@ -0,0 +1,54 @@
+//! Renders deep component trees, where every level wraps the HTML of the
+//! deeper levels as its `children`.
+//!
+//! ```sh
+//! cargo bench -p uibeam --bench render
+//! ```
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use std::hint::black_box;
+use uibeam::{Beam, UI};
+
+struct Level {
+    depth: usize,
+    width: usize,
+    children: UI,
+}
+impl Beam for Level {
+    fn render(self) -> UI {
+        UI! {
+            <section class="level" data-depth={self.depth}>
+                <h2>"Level "{self.depth}</h2>
+                <ul>
+                    {(0..self.width).map(|i| UI! {
+                        <li class="item" data-index={i}>"item #"{i}</li>
+                    })}
+                </ul>
+                <div class="children">{self.children}</div>
+            </section>
+        }
+    }
+}
+
+fn tree(depth: usize, width: usize) -> UI {
+    (0..depth).fold(
+        UI! { <p>"leaf"</p> },
+        |children, depth| UI! { <Level depth={depth} width={width}>{children}</Level> },
+    )
+}
+
+fn render(c: &mut Criterion) {
+    let mut group = c.benchmark_group("render");
+    for (depth, width) in [(10, 3), (50, 3), (200, 3), (50, 30)] {
+        let len = uibeam::shoot(tree(depth, width)).len();
+        group.throughput(Throughput::Bytes(len as u64));
+        group.bench_function(
+            BenchmarkId::from_parameter(format!("depth {depth}, width {width}")),
+            |b| b.iter(|| uibeam::shoot(black_box(tree(depth, width))).len()),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, render);
+criterion_main!(benches);
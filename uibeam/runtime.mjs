@@ -1 +1 @@
-var K,p,$n,hn,T,Nn,Pn,Hn,On,pn,an,cn,ht,W={},Dn=[],pt=/acit|ex(?:s|g|n|p|$)|rph|grid|ows|mnc|ntw|ine[ch]|zoo|^ord|itera/i,X=Array.isArray;function C(t,n){for(var e in n)t[e]=n[e];return t}function vn(t){t&&t.parentNode&&t.parentNode.removeChild(t)}function Fn(t,n,e){var r,i,o,u={};for(o in n)o=="key"?r=n[o]:o=="ref"?i=n[o]:u[o]=n[o];if(arguments.length>2&&(u.children=arguments.length>3?K.call(arguments,2):e),typeof t=="function"&&t.defaultProps!=null)for(o in t.defaultProps)u[o]===void 0&&(u[o]=t.defaultProps[o]);return Z(t,u,r,i,null)}function Z(t,n,e,r,i){var o={type:t,props:n,key:e,ref:r,__k:null,__:null,__b:0,__e:null,__c:null,constructor:void 0,__v:i??++$n,__i:-1,__u:0};return i==null&&p.vnode!=null&&p.vnode(o),o}function vt(){return{current:null}}function $(t){return t.children}function O(t,n){this.props=t,this.context=n}function D(t,n){if(n==null)return t.__?D(t.__,t.__i+1):null;for(var e;n<t.__k.length;n++)if((e=t.__k[n])!=null&&e.__e!=null)return e.__e;return typeof t.type=="function"?D(t):null}function Ln(t){var n,e;if((t=t.__)!=null&&t.__c!=null){for(t.__e=t.__c.base=null,n=0;n<t.__k.length;n++)if((e=t.__k[n])!=null&&e.__e!=null){t.__e=t.__c.base=e.__e;break}return Ln(t)}}function An(t){(!t.__d&&(t.__d=!0)&&T.push(t)&&!z.__r++||Nn!=p.debounceRendering)&&((Nn=p.debounceRendering)||Pn)(z)}function z(){for(var t,n,e,r,i,o,u,f=1;T.length;)T.length>f&&T.sort(Hn),t=T.shift(),f=T.length,t.__d&&(e=void 0,r=void 0,i=(r=(n=t).__v).__e,o=[],u=[],n.__P&&((e=C({},r)).__v=r.__v+1,p.vnode&&p.vnode(e),dn(n.__P,e,r,n.__n,n.__P.namespaceURI,32&r.__u?[i]:null,o,i??D(r),!!(32&r.__u),u),e.__v=r.__v,e.__.__k[e.__i]=e,Wn(o,e,u),r.__e=r.__=null,e.__e!=i&&Ln(e)));z.__r=0}function Rn(t,n,e,r,i,o,u,f,c,s,l){var _,h,a,S,k,w,m,d=r&&r.__k||Dn,E=n.length;for(c=dt(e,n,d,c,E),_=0;_<E;_++)(a=e.__k[_])!=null&&(h=a.__i==-1?W:d[a.__i]||W,a.__i=_,w=dn(t,a,h,i,o,u,f,c,s,l),S=a.__e,a.ref&&h.ref!=a.ref&&(h.ref&&mn(h.ref,null,a),l.push(a.ref,a.__c||S,a)),k==null&&S!=null&&(k=S),(m=!!(4&a.__u))||h.__k===a.__k?c=Mn(a,c,t,m):typeof a.type=="function"&&w!==void 0?c=w:S&&(c=S.nextSibling),a.__u&=-7);return e.__e=k,c}function dt(t,n,e,r,i){var o,u,f,c,s,l=e.length,_=l,h=0;for(t.__k=new Array(i),o=0;o<i;o++)(u=n[o])!=null&&typeof u!="boolean"&&typeof u!="function"?(typeof u=="string"||typeof u=="number"||typeof u=="bigint"||u.constructor==String?u=t.__k[o]=Z(null,u,null,null,null):X(u)?u=t.__k[o]=Z($,{children:u},null,null,null):u.constructor==null&&u.__b>0?u=t.__k[o]=Z(u.type,u.props,u.key,u.ref?u.ref:null,u.__v):t.__k[o]=u,c=o+h,u.__=t,u.__b=t.__b+1,(s=u.__i=mt(u,e,c,_))!=-1&&(_--,(f=e[s])&&(f.__u|=2)),f==null||f.__v==null?(s==-1&&(i>l?h--:i<l&&h++),typeof u.type!="function"&&(u.__u|=4)):s!=c&&(s==c-1?h--:s==c+1?h++:(s>c?h--:h++,u.__u|=4))):t.__k[o]=null;if(_)for(o=0;o<l;o++)(f=e[o])!=null&&(2&f.__u)==0&&(f.__e==r&&(r=D(f)),Vn(f,f));return r}function Mn(t,n,e,r){var i,o;if(typeof t.type=="function"){for(i=t.__k,o=0;i&&o<i.length;o++)i[o]&&(i[o].__=t,n=Mn(i[o],n,e,r));return n}t.__e!=n&&(r&&(n&&t.type&&!n.parentNode&&(n=D(t)),e.insertBefore(t.__e,n||null)),n=t.__e);do n=n&&n.nextSibling;while(n!=null&&n.nodeType==8);return n}function mt(t,n,e,r){var i,o,u,f=t.key,c=t.type,s=n[e],l=s!=null&&(2&s.__u)==0;if(s===null&&f==null||l&&f==s.key&&c==s.type)return e;if(r>(l?1:0)){for(i=e-1,o=e+1;i>=0||o<n.length;)if((s=n[u=i>=0?i--:o++])!=null&&(2&s.__u)==0&&f==s.key&&c==s.type)return u}return-1}function Un(t,n,e){n[0]=="-"?t.setProperty(n,e??""):t[n]=e==null?"":typeof e!="number"||pt.test(n)?e:e+"px"}function B(t,n,e,r,i){var o,u;n:if(n=="style")if(typeof e=="string")t.style.cssText=e;else{if(typeof r=="string"&&(t.style.cssText=r=""),r)for(n in r)e&&n in e||Un(t.style,n,"");if(e)for(n in e)r&&e[n]==r[n]||Un(t.style,n,e[n])}else if(n[0]=="o"&&n[1]=="n")o=n!=(n=n.replace(On,"$1")),u=n.toLowerCase(),n=u in t||n=="onFocusOut"||n=="onFocusIn"?u.slice(2):n.slice(2),t.l||(t.l={}),t.l[n+o]=e,e?r?e.u=r.u:(e.u=pn,t.addEventListener(n,o?cn:an,o)):t.removeEventListener(n,o?cn:an,o);else{if(i=="http://www.w3.org/2000/svg")n=n.replace(/xlink(H|:h)/,"h").replace(/sName$/,"s");else if(n!="width"&&n!="height"&&n!="href"&&n!="list"&&n!="form"&&n!="tabIndex"&&n!="download"&&n!="rowSpan"&&n!="colSpan"&&n!="role"&&n!="popover"&&n in t)try{t[n]=e??"";break n}catch{}typeof e=="function"||(e==null||e===!1&&n[4]!="-"?t.removeAttribute(n):t.setAttribute(n,n=="popover"&&e==1?"":e))}}function Tn(t){return function(n){if(this.l){var e=this.l[n.type+t];if(n.t==null)n.t=pn++;else if(n.t<e.u)return;return e(p.event?p.event(n):n)}}}function dn(t,n,e,r,i,o,u,f,c,s){var l,_,h,a,S,k,w,m,d,E,U,j,R,En,J,M,fn,x=n.type;if(n.constructor!=null)return null;128&e.__u&&(c=!!(32&e.__u),o=[f=n.__e=e.__e]),(l=p.__b)&&l(n);n:if(typeof x=="function")try{if(m=n.props,d="prototype"in x&&x.prototype.render,E=(l=x.contextType)&&r[l.__c],U=l?E?E.props.value:l.__:r,e.__c?w=(_=n.__c=e.__c).__=_.__E:(d?n.__c=_=new x(m,U):(n.__c=_=new O(m,U),_.constructor=x,_.render=gt),E&&E.sub(_),_.state||(_.state={}),_.__n=r,h=_.__d=!0,_.__h=[],_._sb=[]),d&&_.__s==null&&(_.__s=_.state),d&&x.getDerivedStateFromProps!=null&&(_.__s==_.state&&(_.__s=C({},_.__s)),C(_.__s,x.getDerivedStateFromProps(m,_.__s))),a=_.props,S=_.state,_.__v=n,h)d&&x.getDerivedStateFromProps==null&&_.componentWillMount!=null&&_.componentWillMount(),d&&_.componentDidMount!=null&&_.__h.push(_.componentDidMount);else{if(d&&x.getDerivedStateFromProps==null&&m!==a&&_.componentWillReceiveProps!=null&&_.componentWillReceiveProps(m,U),n.__v==e.__v||!_.__e&&_.shouldComponentUpdate!=null&&_.shouldComponentUpdate(m,_.__s,U)===!1){for(n.__v!=e.__v&&(_.props=m,_.state=_.__s,_.__d=!1),n.__e=e.__e,n.__k=e.__k,n.__k.some(function(H){H&&(H.__=n)}),j=0;j<_._sb.length;j++)_.__h.push(_._sb[j]);_._sb=[],_.__h.length&&u.push(_);break n}_.componentWillUpdate!=null&&_.componentWillUpdate(m,_.__s,U),d&&_.componentDidUpdate!=null&&_.__h.push(function(){_.componentDidUpdate(a,S,k)})}if(_.context=U,_.props=m,_.__P=t,_.__e=!1,R=p.__r,En=0,d){for(_.state=_.__s,_.__d=!1,R&&R(n),l=_.render(_.props,_.state,_.context),J=0;J<_._sb.length;J++)_.__h.push(_._sb[J]);_._sb=[]}else do _.__d=!1,R&&R(n),l=_.render(_.props,_.state,_.context),_.state=_.__s;while(_.__d&&++En<25);_.state=_.__s,_.getChildContext!=null&&(r=C(C({},r),_.getChildContext())),d&&!h&&_.getSnapshotBeforeUpdate!=null&&(k=_.getSnapshotBeforeUpdate(a,S)),M=l,l!=null&&l.type===$&&l.key==null&&(M=In(l.props.children)),f=Rn(t,X(M)?M:[M],n,e,r,i,o,u,f,c,s),_.base=n.__e,n.__u&=-161,_.__h.length&&u.push(_),w&&(_.__E=_.__=null)}catch(H){if(n.__v=null,c||o!=null)if(H.then){for(n.__u|=c?160:128;f&&f.nodeType==8&&f.nextSibling;)f=f.nextSibling;o[o.indexOf(f)]=null,n.__e=f}else{for(fn=o.length;fn--;)vn(o[fn]);ln(n)}else n.__e=e.__e,n.__k=e.__k,H.then||ln(n);p.__e(H,n,e)}else o==null&&n.__v==e.__v?(n.__k=e.__k,n.__e=e.__e):f=n.__e=yt(e.__e,n,e,r,i,o,u,c,s);return(l=p.diffed)&&l(n),128&n.__u?void 0:f}function ln(t){t&&t.__c&&(t.__c.__e=!0),t&&t.__k&&t.__k.forEach(ln)}function Wn(t,n,e){for(var r=0;r<e.length;r++)mn(e[r],e[++r],e[++r]);p.__c&&p.__c(n,t),t.some(function(i){try{t=i.__h,i.__h=[],t.some(function(o){o.call(i)})}catch(o){p.__e(o,i.__v)}})}function In(t){return typeof t!="object"||t==null||t.__b&&t.__b>0?t:X(t)?t.map(In):C({},t)}function yt(t,n,e,r,i,o,u,f,c){var s,l,_,h,a,S,k,w=e.props||W,m=n.props,d=n.type;if(d=="svg"?i="http://www.w3.org/2000/svg":d=="math"?i="http://www.w3.org/1998/Math/MathML":i||(i="http://www.w3.org/1999/xhtml"),o!=null){for(s=0;s<o.length;s++)if((a=o[s])&&"setAttribute"in a==!!d&&(d?a.localName==d:a.nodeType==3)){t=a,o[s]=null;break}}if(t==null){if(d==null)return document.createTextNode(m);t=document.createElementNS(i,d,m.is&&m),f&&(p.__m&&p.__m(n,o),f=!1),o=null}if(d==null)w===m||f&&t.data==m||(t.data=m);else{if(o=o&&K.call(t.childNodes),!f&&o!=null)for(w={},s=0;s<t.attributes.length;s++)w[(a=t.attributes[s]).name]=a.value;for(s in w)if(a=w[s],s!="children"){if(s=="dangerouslySetInnerHTML")_=a;else if(!(s in m)){if(s=="value"&&"defaultValue"in m||s=="checked"&&"defaultChecked"in m)continue;B(t,s,null,a,i)}}for(s in m)a=m[s],s=="children"?h=a:s=="dangerouslySetInnerHTML"?l=a:s=="value"?S=a:s=="checked"?k=a:f&&typeof a!="function"||w[s]===a||B(t,s,a,w[s],i);if(l)f||_&&(l.__html==_.__html||l.__html==t.innerHTML)||(t.innerHTML=l.__html),n.__k=[];else if(_&&(t.innerHTML=""),Rn(n.type=="template"?t.content:t,X(h)?h:[h],n,e,r,d=="foreignObject"?"http://www.w3.org/1999/xhtml":i,o,u,o?o[0]:e.__k&&D(e,0),f,c),o!=null)for(s=o.length;s--;)vn(o[s]);f||(s="value",d=="progress"&&S==null?t.removeAttribute("value"):S!=null&&(S!==t[s]||d=="progress"&&!S||d=="option"&&S!=w[s])&&B(t,s,S,w[s],i),s="checked",k!=null&&k!=t[s]&&B(t,s,k,w[s],i))}return t}function mn(t,n,e){try{if(typeof t=="function"){var r=typeof t.__u=="function";r&&t.__u(),r&&n==null||(t.__u=t(n))}else t.current=n}catch(i){p.__e(i,e)}}function Vn(t,n,e){var r,i;if(p.unmount&&p.unmount(t),(r=t.ref)&&(r.current&&r.current!=t.__e||mn(r,null,n)),(r=t.__c)!=null){if(r.componentWillUnmount)try{r.componentWillUnmount()}catch(o){p.__e(o,n)}r.base=r.__P=null}if(r=t.__k)for(i=0;i<r.length;i++)r[i]&&Vn(r[i],n,e||typeof t.type!="function");e||vn(t.__e),t.__c=t.__=t.__e=void 0}function gt(t,n,e){return this.constructor(t,e)}function bt(t,n,e){var r,i,o,u;n==document&&(n=document.documentElement),p.__&&p.__(t,n),i=(r=typeof e=="function")?null:e&&e.__k||n.__k,o=[],u=[],dn(n,t=(!r&&e||n).__k=Fn($,null,[t]),i||W,W,n.namespaceURI,!r&&e?[e]:i?null:n.firstChild?K.call(n.childNodes):null,o,!r&&e?e:i?i.__e:n.firstChild,r,u),Wn(o,t,u)}function qn(t,n){bt(t,n,qn)}K=Dn.slice,p={__e:function(t,n,e,r){for(var i,o,u;n=n.__;)if((i=n.__c)&&!i.__)try{if((o=i.constructor)&&o.getDerivedStateFromError!=null&&(i.setState(o.getDerivedStateFromError(t)),u=i.__d),i.componentDidCatch!=null&&(i.componentDidCatch(t,r||{}),u=i.__d),u)return i.__E=i}catch(f){t=f}throw t}},$n=0,hn=function(t){return t!=null&&t.constructor==null},O.prototype.setState=function(t,n){var e;e=this.__s!=null&&this.__s!=this.state?this.__s:this.__s=C({},this.state),typeof t=="function"&&(t=t(C({},e),this.props)),t&&C(e,t),t!=null&&this.__v&&(n&&this._sb.push(n),An(this))},O.prototype.forceUpdate=function(t){this.__v&&(this.__e=!0,t&&this.__h.push(t),An(this))},O.prototype.render=$,T=[],Pn=typeof Promise=="function"?Promise.prototype.then.bind(Promise.resolve()):setTimeout,Hn=function(t,n){return t.__v.__b-n.__v.__b},z.__r=0,On=/(PointerCapture)$|Capture$/i,pn=0,an=Tn(!1),cn=Tn(!0),ht=0;var I,y,yn,Gn,Q=0,Yn=[],g=p,jn=g.__b,Jn=g.__r,Bn=g.diffed,Zn=g.__c,zn=g.unmount,Kn=g.__;function bn(t,n){g.__h&&g.__h(y,t,Q||n),Q=0;var e=y.__H||(y.__H={__:[],__h:[]});return t>=e.__.length&&e.__.push({}),e.__[t]}function Qn(t){return Q=1,St(et,t)}function St(t,n,e){var r=bn(I++,2);if(r.t=t,!r.__c&&(r.__=[e?e(n):et(void 0,n),function(f){var c=r.__N?r.__N[0]:r.__[0],s=r.t(c,f);c!==s&&(r.__N=[s,r.__[1]],r.__c.setState({}))}],r.__c=y,!y.__f)){var i=function(f,c,s){if(!r.__c.__H)return!0;var l=r.__c.__H.__.filter(function(h){return!!h.__c});if(l.every(function(h){return!h.__N}))return!o||o.call(this,f,c,s);var _=r.__c.props!==f;return l.forEach(function(h){if(h.__N){var a=h.__[0];h.__=h.__N,h.__N=void 0,a!==h.__[0]&&(_=!0)}}),o&&o.call(this,f,c,s)||_};y.__f=!0;var o=y.shouldComponentUpdate,u=y.componentWillUpdate;y.componentWillUpdate=function(f,c,s){if(this.__e){var l=o;o=void 0,i(f,c,s),o=l}u&&u.call(this,f,c,s)},y.shouldComponentUpdate=i}return r.__N||r.__}function nt(t,n){var e=bn(I++,3);!g.__s&&tt(e.__H,n)&&(e.__=t,e.u=n,y.__H.__h.push(e))}function Sn(t){return Q=5,nn(function(){return{current:t}},[])}function nn(t,n){var e=bn(I++,7);return tt(e.__H,n)&&(e.__=t(),e.__H=n,e.__h=t),e.__}function wt(){for(var t;t=Yn.shift();)if(t.__P&&t.__H)try{t.__H.__h.forEach(Y),t.__H.__h.forEach(gn),t.__H.__h=[]}catch(n){t.__H.__h=[],g.__e(n,t.__v)}}g.__b=function(t){y=null,jn&&jn(t)},g.__=function(t,n){t&&n.__k&&n.__k.__m&&(t.__m=n.__k.__m),Kn&&Kn(t,n)},g.__r=function(t){Jn&&Jn(t),I=0;var n=(y=t.__c).__H;n&&(yn===y?(n.__h=[],y.__h=[],n.__.forEach(function(e){e.__N&&(e.__=e.__N),e.u=e.__N=void 0})):(n.__h.forEach(Y),n.__h.forEach(gn),n.__h=[],I=0)),yn=y},g.diffed=function(t){Bn&&Bn(t);var n=t.__c;n&&n.__H&&(n.__H.__h.length&&(Yn.push(n)!==1&&Gn===g.requestAnimationFrame||((Gn=g.requestAnimationFrame)||kt)(wt)),n.__H.__.forEach(function(e){e.u&&(e.__H=e.u),e.u=void 0})),yn=y=null},g.__c=function(t,n){n.some(function(e){try{e.__h.forEach(Y),e.__h=e.__h.filter(function(r){return!r.__||gn(r)})}catch(r){n.some(function(i){i.__h&&(i.__h=[])}),n=[],g.__e(r,e.__v)}}),Zn&&Zn(t,n)},g.unmount=function(t){zn&&zn(t);var n,e=t.__c;e&&e.__H&&(e.__H.__.forEach(function(r){try{Y(r)}catch(i){n=i}}),e.__H=void 0,n&&g.__e(n,e.__v))};var Xn=typeof requestAnimationFrame=="function";function kt(t){var n,e=function(){clearTimeout(r),Xn&&cancelAnimationFrame(n),setTimeout(t)},r=setTimeout(e,35);Xn&&(n=requestAnimationFrame(e))}function Y(t){var n=y,e=t.__c;typeof e=="function"&&(t.__c=void 0,e()),y=n}function gn(t){var n=y;t.__c=t.__(),y=n}function tt(t,n){return!t||t.length!==n.length||n.some(function(e,r){return e!==t[r]})}function et(t,n){return typeof n=="function"?n(t):n}var xt=Symbol.for("preact-signals");function en(){if(N>1)N--;else{for(var t,n=!1;V!==void 0;){var e=V;for(V=void 0,wn++;e!==void 0;){var r=e.o;if(e.o=void 0,e.f&=-3,!(8&e.f)&&ot(e))try{e.c()}catch(i){n||(t=i,n=!0)}e=r}}if(wn=0,N--,n)throw t}}function q(t){if(N>0)return t();N++;try{return t()}finally{en()}}var v=void 0;function rn(t){var n=v;v=void 0;try{return t()}finally{v=n}}var V=void 0,N=0,wn=0,tn=0;function rt(t){if(v!==void 0){var n=t.n;if(n===void 0||n.t!==v)return n={i:0,S:t,p:v.s,n:void 0,t:v,e:void 0,x:void 0,r:n},v.s!==void 0&&(v.s.n=n),v.s=n,t.n=n,32&v.f&&t.S(n),n;if(n.i===-1)return n.i=0,n.n!==void 0&&(n.n.p=n.p,n.p!==void 0&&(n.p.n=n.n),n.p=v.s,n.n=void 0,v.s.n=n,v.s=n),n}}function b(t,n){this.v=t,this.i=0,this.n=void 0,this.t=void 0,this.W=n?.watched,this.Z=n?.unwatched,this.name=n?.name}b.prototype.brand=xt;b.prototype.h=function(){return!0};b.prototype.S=function(t){var n=this,e=this.t;e!==t&&t.e===void 0&&(t.x=e,this.t=t,e!==void 0?e.e=t:rn(function(){var r;(r=n.W)==null||r.call(n)}))};b.prototype.U=function(t){var n=this;if(this.t!==void 0){var e=t.e,r=t.x;e!==void 0&&(e.x=r,t.e=void 0),r!==void 0&&(r.e=e,t.x=void 0),t===this.t&&(this.t=r,r===void 0&&rn(function(){var i;(i=n.Z)==null||i.call(n)}))}};b.prototype.subscribe=function(t){var n=this;return A(function(){var e=n.value,r=v;v=void 0;try{t(e)}finally{v=r}},{name:"sub"})};b.prototype.valueOf=function(){return this.value};b.prototype.toString=function(){return this.value+""};b.prototype.toJSON=function(){return this.value};b.prototype.peek=function(){var t=v;v=void 0;try{return this.value}finally{v=t}};Object.defineProperty(b.prototype,"value",{get:function(){var t=rt(this);return t!==void 0&&(t.i=this.i),this.v},set:function(t){if(t!==this.v){if(wn>100)throw new Error("Cycle detected");this.v=t,this.i++,tn++,N++;try{for(var n=this.t;n!==void 0;n=n.x)n.t.N()}finally{en()}}}});function on(t,n){return new b(t,n)}function ot(t){for(var n=t.s;n!==void 0;n=n.n)if(n.S.i!==n.i||!n.S.h()||n.S.i!==n.i)return!0;return!1}function it(t){for(var n=t.s;n!==void 0;n=n.n){var e=n.S.n;if(e!==void 0&&(n.r=e),n.S.n=n,n.i=-1,n.n===void 0){t.s=n;break}}}function _t(t){for(var n=t.s,e=void 0;n!==void 0;){var r=n.p;n.i===-1?(n.S.U(n),r!==void 0&&(r.n=n.n),n.n!==void 0&&(n.n.p=r)):e=n,n.S.n=n.r,n.r!==void 0&&(n.r=void 0),n=r}t.s=e}function P(t,n){b.call(this,void 0),this.x=t,this.s=void 0,this.g=tn-1,this.f=4,this.W=n?.watched,this.Z=n?.unwatched,this.name=n?.name}P.prototype=new b;P.prototype.h=function(){if(this.f&=-3,1&this.f)return!1;if((36&this.f)==32||(this.f&=-5,this.g===tn))return!0;if(this.g=tn,this.f|=1,this.i>0&&!ot(this))return this.f&=-2,!0;var t=v;try{it(this),v=this;var n=this.x();(16&this.f||this.v!==n||this.i===0)&&(this.v=n,this.f&=-17,this.i++)}catch(e){this.v=e,this.f|=16,this.i++}return v=t,_t(this),this.f&=-2,!0};P.prototype.S=function(t){if(this.t===void 0){this.f|=36;for(var n=this.s;n!==void 0;n=n.n)n.S.S(n)}b.prototype.S.call(this,t)};P.prototype.U=function(t){if(this.t!==void 0&&(b.prototype.U.call(this,t),this.t===void 0)){this.f&=-33;for(var n=this.s;n!==void 0;n=n.n)n.S.U(n)}};P.prototype.N=function(){if(!(2&this.f)){this.f|=6;for(var t=this.t;t!==void 0;t=t.x)t.t.N()}};Object.defineProperty(P.prototype,"value",{get:function(){if(1&this.f)throw new Error("Cycle detected");var t=rt(this);if(this.h(),t!==void 0&&(t.i=this.i),16&this.f)throw this.v;return this.v}});function G(t,n){return new P(t,n)}function ut(t){var n=t.u;if(t.u=void 0,typeof n=="function"){N++;var e=v;v=void 0;try{n()}catch(r){throw t.f&=-2,t.f|=8,kn(t),r}finally{v=e,en()}}}function kn(t){for(var n=t.s;n!==void 0;n=n.n)n.S.U(n);t.x=void 0,t.s=void 0,ut(t)}function Ct(t){if(v!==this)throw new Error("Out-of-order effect");_t(this),v=t,this.f&=-2,8&this.f&&kn(this),en()}function F(t,n){this.x=t,this.u=void 0,this.s=void 0,this.o=void 0,this.f=32,this.name=n?.name}F.prototype.c=function(){var t=this.S();try{if(8&this.f||this.x===void 0)return;var n=this.x();typeof n=="function"&&(this.u=n)}finally{t()}};F.prototype.S=function(){if(1&this.f)throw new Error("Cycle detected");this.f|=1,this.f&=-9,ut(this),it(this),N++;var t=v;return v=this,Ct.bind(this,t)};F.prototype.N=function(){2&this.f||(this.f|=2,this.o=V,V=this)};F.prototype.d=function(){this.f|=8,1&this.f||kn(this)};F.prototype.dispose=function(){this.d()};function A(t,n){var e=new F(t,n);try{e.c()}catch(i){throw e.d(),i}var r=e.d.bind(e);return r[Symbol.dispose]=r,r}var Cn,un,xn,sn=typeof window<"u"&&!!window.__PREACT_SIGNALS_DEVTOOLS__,st=[],ft=[];A(function(){Cn=this.N})();function L(t,n){p[t]=n.bind(null,p[t]||function(){})}function _n(t){xn&&xn(),xn=t&&t.S()}function at(t){var n=this,e=t.data,r=ct(e);r.value=e;var i=nn(function(){for(var f=n,c=n.__v;c=c.__;)if(c.__c){c.__c.__$f|=4;break}var s=G(function(){var a=r.value.value;return a===0?0:a===!0?"":a||""}),l=G(function(){return!Array.isArray(s.value)&&!hn(s.value)}),_=A(function(){if(this.N=lt,l.value){var a=s.value;f.__v&&f.__v.__e&&f.__v.__e.nodeType===3&&(f.__v.__e.data=a)}}),h=n.__$u.d;return n.__$u.d=function(){_(),h.call(this)},[l,s]},[]),o=i[0],u=i[1];return o.value?u.peek():u.value}at.displayName="ReactiveTextNode";Object.defineProperties(b.prototype,{constructor:{configurable:!0,value:void 0},type:{configurable:!0,value:at},props:{configurable:!0,get:function(){return{data:this}}},__b:{configurable:!0,value:1}});L("__b",function(t,n){if(sn&&typeof n.type=="function"&&window.__PREACT_SIGNALS_DEVTOOLS__.exitComponent(),typeof n.type=="string"){var e,r=n.props;for(var i in r)if(i!=="children"){var o=r[i];o instanceof b&&(e||(n.__np=e={}),e[i]=o,r[i]=o.peek())}}t(n)});L("__r",function(t,n){if(sn&&typeof n.type=="function"&&window.__PREACT_SIGNALS_DEVTOOLS__.enterComponent(n),n.type!==$){_n();var e,r=n.__c;r&&(r.__$f&=-2,(e=r.__$u)===void 0&&(r.__$u=e=(function(i){var o;return A(function(){o=this}),o.c=function(){r.__$f|=1,r.setState({})},o})())),un=r,_n(e)}t(n)});L("__e",function(t,n,e,r){sn&&window.__PREACT_SIGNALS_DEVTOOLS__.exitComponent(),_n(),un=void 0,t(n,e,r)});L("diffed",function(t,n){sn&&typeof n.type=="function"&&window.__PREACT_SIGNALS_DEVTOOLS__.exitComponent(),_n(),un=void 0;var e;if(typeof n.type=="string"&&(e=n.__e)){var r=n.__np,i=n.props;if(r){var o=e.U;if(o)for(var u in o){var f=o[u];f!==void 0&&!(u in r)&&(f.d(),o[u]=void 0)}else o={},e.U=o;for(var c in r){var s=o[c],l=r[c];s===void 0?(s=Et(e,c,l,i),o[c]=s):s.o(l,i)}}}t(n)});function Et(t,n,e,r){var i=n in t&&t.ownerSVGElement===void 0,o=on(e);return{o:function(u,f){o.value=u,r=f},d:A(function(){this.N=lt;var u=o.value.value;r[n]!==u&&(r[n]=u,i?t[n]=u:u!=null&&(u!==!1||n[4]==="-")?t.setAttribute(n,u):t.removeAttribute(n))})}}L("unmount",function(t,n){if(typeof n.type=="string"){var e=n.__e;if(e){var r=e.U;if(r){e.U=void 0;for(var i in r){var o=r[i];o&&o.d()}}}}else{var u=n.__c;if(u){var f=u.__$u;f&&(u.__$u=void 0,f.d())}}t(n)});L("__h",function(t,n,e,r){(r<3||r===9)&&(n.__$f|=2),t(n,e,r)});O.prototype.shouldComponentUpdate=function(t,n){var e=this.__$u,r=e&&e.s!==void 0;for(var i in n)return!0;if(this.__f||typeof this.u=="boolean"&&this.u===!0){var o=2&this.__$f;if(!(r||o||4&this.__$f)||1&this.__$f)return!0}else if(!(r||4&this.__$f)||3&this.__$f)return!0;for(var u in t)if(u!=="__source"&&t[u]!==this.props[u])return!0;for(var f in this.props)if(!(f in t))return!0;return!1};function ct(t,n){return Qn(function(){return on(t,n)})[0]}function Nt(t,n){var e=Sn(t);return e.current=t,un.__$f|=4,nn(function(){return G(function(){return e.current()},n)},[])}var At=typeof requestAnimationFrame>"u"?setTimeout:function(t){var n=function(){clearTimeout(e),cancelAnimationFrame(r),t()},e=setTimeout(n,35),r=requestAnimationFrame(n)},Ut=function(t){queueMicrotask(function(){queueMicrotask(t)})};function Tt(){q(function(){for(var t;t=st.shift();)Cn.call(t)})}function $t(){st.push(this)===1&&(p.requestAnimationFrame||At)(Tt)}function Pt(){q(function(){for(var t;t=ft.shift();)Cn.call(t)})}function lt(){ft.push(this)===1&&(p.requestAnimationFrame||Ut)(Pt)}function Ht(t,n){var e=Sn(t);e.current=t,nt(function(){return A(function(){return this.N=$t,e.current()},n)},[])}(async()=>{let{default:t,...n}=await import("/.uibeam/hydrate.js");await t(),document.querySelectorAll("[data-uibeam-hydrater]").forEach(e=>{let r=e.getAttribute("data-uibeam-hydrater");if(!r){console.error(`[uibeam] no hydrater name: ${e}`);return}let i=n[r];if(!i){console.error(`[uibeam] no hydrater found for name '${r}': ${e}`);return}let o=e.getAttribute("data-uibeam-props");if(!o){console.error(`[uibeam] no props string: ${e}`);return}let u=null;try{u=JSON.parse(o)}catch(f){console.error(`[uibeam] failed to parse props JSON '${o}': ${f}`);return}if(!u){console.error(`[uibeam] no props parsed: ${e}`);return}try{i(u,e)}catch(f){let c=f instanceof Error?f.message:String(f);console.error(`[uibeam] failed to hydrate with '${r}' and props '${JSON.stringify(o)}': ${c}`)}})})();export{$ as Fragment,q as batch,Fn as createElement,vt as createRef,qn as hydrate,rn as untracked,Nt as useComputed,ct as useSignal,Ht as useSignalEffect};
+var K,p,$n,hn,T,Nn,Pn,Hn,On,pn,an,cn,ht,W={},Dn=[],pt=/acit|ex(?:s|g|n|p|$)|rph|grid|ows|mnc|ntw|ine[ch]|zoo|^ord|itera/i,X=Array.isArray;function C(t,n){for(var e in n)t[e]=n[e];return t}function vn(t){t&&t.parentNode&&t.parentNode.removeChild(t)}function Fn(t,n,e){var r,i,o,u={};for(o in n)o=="key"?r=n[o]:o=="ref"?i=n[o]:u[o]=n[o];if(arguments.length>2&&(u.children=arguments.length>3?K.call(arguments,2):e),typeof t=="function"&&t.defaultProps!=null)for(o in t.defaultProps)u[o]===void 0&&(u[o]=t.defaultProps[o]);return Z(t,u,r,i,null)}function Z(t,n,e,r,i){var o={type:t,props:n,key:e,ref:r,__k:null,__:null,__b:0,__e:null,__c:null,constructor:void 0,__v:i??++$n,__i:-1,__u:0};return i==null&&p.vnode!=null&&p.vnode(o),o}function vt(){return{current:null}}function $(t){return t.children}function O(t,n){this.props=t,this.context=n}function D(t,n){if(n==null)return t.__?D(t.__,t.__i+1):null;for(var e;n<t.__k.length;n++)if((e=t.__k[n])!=null&&e.__e!=null)return e.__e;return typeof t.type=="function"?D(t):null}function Ln(t){var n,e;if((t=t.__)!=null&&t.__c!=null){for(t.__e=t.__c.base=null,n=0;n<t.__k.length;n++)if((e=t.__k[n])!=null&&e.__e!=null){t.__e=t.__c.base=e.__e;break}return Ln(t)}}function An(t){(!t.__d&&(t.__d=!0)&&T.push(t)&&!z.__r++||Nn!=p.debounceRendering)&&((Nn=p.debounceRendering)||Pn)(z)}function z(){for(var t,n,e,r,i,o,u,f=1;T.length;)T.length>f&&T.sort(Hn),t=T.shift(),f=T.length,t.__d&&(e=void 0,r=void 0,i=(r=(n=t).__v).__e,o=[],u=[],n.__P&&((e=C({},r)).__v=r.__v+1,p.vnode&&p.vnode(e),dn(n.__P,e,r,n.__n,n.__P.namespaceURI,32&r.__u?[i]:null,o,i??D(r),!!(32&r.__u),u),e.__v=r.__v,e.__.__k[e.__i]=e,Wn(o,e,u),r.__e=r.__=null,e.__e!=i&&Ln(e)));z.__r=0}function Rn(t,n,e,r,i,o,u,f,c,s,l){var _,h,a,S,k,w,m,d=r&&r.__k||Dn,E=n.length;for(c=dt(e,n,d,c,E),_=0;_<E;_++)(a=e.__k[_])!=null&&(h=a.__i==-1?W:d[a.__i]||W,a.__i=_,w=dn(t,a,h,i,o,u,f,c,s,l),S=a.__e,a.ref&&h.ref!=a.ref&&(h.ref&&mn(h.ref,null,a),l.push(a.ref,a.__c||S,a)),k==null&&S!=null&&(k=S),(m=!!(4&a.__u))||h.__k===a.__k?c=Mn(a,c,t,m):typeof a.type=="function"&&w!==void 0?c=w:S&&(c=S.nextSibling),a.__u&=-7);return e.__e=k,c}function dt(t,n,e,r,i){var o,u,f,c,s,l=e.length,_=l,h=0;for(t.__k=new Array(i),o=0;o<i;o++)(u=n[o])!=null&&typeof u!="boolean"&&typeof u!="function"?(typeof u=="string"||typeof u=="number"||typeof u=="bigint"||u.constructor==String?u=t.__k[o]=Z(null,u,null,null,null):X(u)?u=t.__k[o]=Z($,{children:u},null,null,null):u.constructor==null&&u.__b>0?u=t.__k[o]=Z(u.type,u.props,u.key,u.ref?u.ref:null,u.__v):t.__k[o]=u,c=o+h,u.__=t,u.__b=t.__b+1,(s=u.__i=mt(u,e,c,_))!=-1&&(_--,(f=e[s])&&(f.__u|=2)),f==null||f.__v==null?(s==-1&&(i>l?h--:i<l&&h++),typeof u.type!="function"&&(u.__u|=4)):s!=c&&(s==c-1?h--:s==c+1?h++:(s>c?h--:h++,u.__u|=4))):t.__k[o]=null;if(_)for(o=0;o<l;o++)(f=e[o])!=null&&(2&f.__u)==0&&(f.__e==r&&(r=D(f)),Vn(f,f));return r}function Mn(t,n,e,r){var i,o;if(typeof t.type=="function"){for(i=t.__k,o=0;i&&o<i.length;o++)i[o]&&(i[o].__=t,n=Mn(i[o],n,e,r));return n}t.__e!=n&&(r&&(n&&t.type&&!n.parentNode&&(n=D(t)),e.insertBefore(t.__e,n||null)),n=t.__e);do n=n&&n.nextSibling;while(n!=null&&n.nodeType==8);return n}function mt(t,n,e,r){var i,o,u,f=t.key,c=t.type,s=n[e],l=s!=null&&(2&s.__u)==0;if(s===null&&f==null||l&&f==s.key&&c==s.type)return e;if(r>(l?1:0)){for(i=e-1,o=e+1;i>=0||o<n.length;)if((s=n[u=i>=0?i--:o++])!=null&&(2&s.__u)==0&&f==s.key&&c==s.type)return u}return-1}function Un(t,n,e){n[0]=="-"?t.setProperty(n,e??""):t[n]=e==null?"":typeof e!="number"||pt.test(n)?e:e+"px"}function B(t,n,e,r,i){var o,u;n:if(n=="style")if(typeof e=="string")t.style.cssText=e;else{if(typeof r=="string"&&(t.style.cssText=r=""),r)for(n in r)e&&n in e||Un(t.style,n,"");if(e)for(n in e)r&&e[n]==r[n]||Un(t.style,n,e[n])}else if(n[0]=="o"&&n[1]=="n")o=n!=(n=n.replace(On,"$1")),u=n.toLowerCase(),n=u in t||n=="onFocusOut"||n=="onFocusIn"?u.slice(2):n.slice(2),t.l||(t.l={}),t.l[n+o]=e,e?r?e.u=r.u:(e.u=pn,t.addEventListener(n,o?cn:an,o)):t.removeEventListener(n,o?cn:an,o);else{if(i=="http://www.w3.org/2000/svg")n=n.replace(/xlink(H|:h)/,"h").replace(/sName$/,"s");else if(n!="width"&&n!="height"&&n!="href"&&n!="list"&&n!="form"&&n!="tabIndex"&&n!="download"&&n!="rowSpan"&&n!="colSpan"&&n!="role"&&n!="popover"&&n in t)try{t[n]=e??"";break n}catch{}typeof e=="function"||(e==null||e===!1&&n[4]!="-"?t.removeAttribute(n):t.setAttribute(n,n=="popover"&&e==1?"":e))}}function Tn(t){return function(n){if(this.l){var e=this.l[n.type+t];if(n.t==null)n.t=pn++;else if(n.t<e.u)return;return e(p.event?p.event(n):n)}}}function dn(t,n,e,r,i,o,u,f,c,s){var l,_,h,a,S,k,w,m,d,E,U,j,R,En,J,M,fn,x=n.type;if(n.constructor!=null)return null;128&e.__u&&(c=!!(32&e.__u),o=[f=n.__e=e.__e]),(l=p.__b)&&l(n);n:if(typeof x=="function")try{if(m=n.props,d="prototype"in x&&x.prototype.render,E=(l=x.contextType)&&r[l.__c],U=l?E?E.props.value:l.__:r,e.__c?w=(_=n.__c=e.__c).__=_.__E:(d?n.__c=_=new x(m,U):(n.__c=_=new O(m,U),_.constructor=x,_.render=gt),E&&E.sub(_),_.state||(_.state={}),_.__n=r,h=_.__d=!0,_.__h=[],_._sb=[]),d&&_.__s==null&&(_.__s=_.state),d&&x.getDerivedStateFromProps!=null&&(_.__s==_.state&&(_.__s=C({},_.__s)),C(_.__s,x.getDerivedStateFromProps(m,_.__s))),a=_.props,S=_.state,_.__v=n,h)d&&x.getDerivedStateFromProps==null&&_.componentWillMount!=null&&_.componentWillMount(),d&&_.componentDidMount!=null&&_.__h.push(_.componentDidMount);else{if(d&&x.getDerivedStateFromProps==null&&m!==a&&_.componentWillReceiveProps!=null&&_.componentWillReceiveProps(m,U),n.__v==e.__v||!_.__e&&_.shouldComponentUpdate!=null&&_.shouldComponentUpdate(m,_.__s,U)===!1){for(n.__v!=e.__v&&(_.props=m,_.state=_.__s,_.__d=!1),n.__e=e.__e,n.__k=e.__k,n.__k.some(function(H){H&&(H.__=n)}),j=0;j<_._sb.length;j++)_.__h.push(_._sb[j]);_._sb=[],_.__h.length&&u.push(_);break n}_.componentWillUpdate!=null&&_.componentWillUpdate(m,_.__s,U),d&&_.componentDidUpdate!=null&&_.__h.push(function(){_.componentDidUpdate(a,S,k)})}if(_.context=U,_.props=m,_.__P=t,_.__e=!1,R=p.__r,En=0,d){for(_.state=_.__s,_.__d=!1,R&&R(n),l=_.render(_.props,_.state,_.context),J=0;J<_._sb.length;J++)_.__h.push(_._sb[J]);_._sb=[]}else do _.__d=!1,R&&R(n),l=_.render(_.props,_.state,_.context),_.state=_.__s;while(_.__d&&++En<25);_.state=_.__s,_.getChildContext!=null&&(r=C(C({},r),_.getChildContext())),d&&!h&&_.getSnapshotBeforeUpdate!=null&&(k=_.getSnapshotBeforeUpdate(a,S)),M=l,l!=null&&l.type===$&&l.key==null&&(M=In(l.props.children)),f=Rn(t,X(M)?M:[M],n,e,r,i,o,u,f,c,s),_.base=n.__e,n.__u&=-161,_.__h.length&&u.push(_),w&&(_.__E=_.__=null)}catch(H){if(n.__v=null,c||o!=null)if(H.then){for(n.__u|=c?160:128;f&&f.nodeType==8&&f.nextSibling;)f=f.nextSibling;o[o.indexOf(f)]=null,n.__e=f}else{for(fn=o.length;fn--;)vn(o[fn]);ln(n)}else n.__e=e.__e,n.__k=e.__k,H.then||ln(n);p.__e(H,n,e)}else o==null&&n.__v==e.__v?(n.__k=e.__k,n.__e=e.__e):f=n.__e=yt(e.__e,n,e,r,i,o,u,c,s);return(l=p.diffed)&&l(n),128&n.__u?void 0:f}function ln(t){t&&t.__c&&(t.__c.__e=!0),t&&t.__k&&t.__k.forEach(ln)}function Wn(t,n,e){for(var r=0;r<e.length;r++)mn(e[r],e[++r],e[++r]);p.__c&&p.__c(n,t),t.some(function(i){try{t=i.__h,i.__h=[],t.some(function(o){o.call(i)})}catch(o){p.__e(o,i.__v)}})}function In(t){return typeof t!="object"||t==null||t.__b&&t.__b>0?t:X(t)?t.map(In):C({},t)}function yt(t,n,e,r,i,o,u,f,c){var s,l,_,h,a,S,k,w=e.props||W,m=n.props,d=n.type;if(d=="svg"?i="http://www.w3.org/2000/svg":d=="math"?i="http://www.w3.org/1998/Math/MathML":i||(i="http://www.w3.org/1999/xhtml"),o!=null){for(s=0;s<o.length;s++)if((a=o[s])&&"setAttribute"in a==!!d&&(d?a.localName==d:a.nodeType==3)){t=a,o[s]=null;break}}if(t==null){if(d==null)return document.createTextNode(m);t=document.createElementNS(i,d,m.is&&m),f&&(p.__m&&p.__m(n,o),f=!1),o=null}if(d==null)w===m||f&&t.data==m||(t.data=m);else{if(o=o&&K.call(t.childNodes),!f&&o!=null)for(w={},s=0;s<t.attributes.length;s++)w[(a=t.attributes[s]).name]=a.value;for(s in w)if(a=w[s],s!="children"){if(s=="dangerouslySetInnerHTML")_=a;else if(!(s in m)){if(s=="value"&&"defaultValue"in m||s=="checked"&&"defaultChecked"in m)continue;B(t,s,null,a,i)}}for(s in m)a=m[s],s=="children"?h=a:s=="dangerouslySetInnerHTML"?l=a:s=="value"?S=a:s=="checked"?k=a:f&&typeof a!="function"||w[s]===a||B(t,s,a,w[s],i);if(l)f||_&&(l.__html==_.__html||l.__html==t.innerHTML)||(t.innerHTML=l.__html),n.__k=[];else if(_&&(t.innerHTML=""),Rn(n.type=="template"?t.content:t,X(h)?h:[h],n,e,r,d=="foreignObject"?"http://www.w3.org/1999/xhtml":i,o,u,o?o[0]:e.__k&&D(e,0),f,c),o!=null)for(s=o.length;s--;)vn(o[s]);f||(s="value",d=="progress"&&S==null?t.removeAttribute("value"):S!=null&&(S!==t[s]||d=="progress"&&!S||d=="option"&&S!=w[s])&&B(t,s,S,w[s],i),s="checked",k!=null&&k!=t[s]&&B(t,s,k,w[s],i))}return t}function mn(t,n,e){try{if(typeof t=="function"){var r=typeof t.__u=="function";r&&t.__u(),r&&n==null||(t.__u=t(n))}else t.current=n}catch(i){p.__e(i,e)}}function Vn(t,n,e){var r,i;if(p.unmount&&p.unmount(t),(r=t.ref)&&(r.current&&r.current!=t.__e||mn(r,null,n)),(r=t.__c)!=null){if(r.componentWillUnmount)try{r.componentWillUnmount()}catch(o){p.__e(o,n)}r.base=r.__P=null}if(r=t.__k)for(i=0;i<r.length;i++)r[i]&&Vn(r[i],n,e||typeof t.type!="function");e||vn(t.__e),t.__c=t.__=t.__e=void 0}function gt(t,n,e){return this.constructor(t,e)}function bt(t,n,e){var r,i,o,u;n==document&&(n=document.documentElement),p.__&&p.__(t,n),i=(r=typeof e=="function")?null:e&&e.__k||n.__k,o=[],u=[],dn(n,t=(!r&&e||n).__k=Fn($,null,[t]),i||W,W,n.namespaceURI,!r&&e?[e]:i?null:n.firstChild?K.call(n.childNodes):null,o,!r&&e?e:i?i.__e:n.firstChild,r,u),Wn(o,t,u)}function qn(t,n){bt(t,n,qn)}K=Dn.slice,p={__e:function(t,n,e,r){for(var i,o,u;n=n.__;)if((i=n.__c)&&!i.__)try{if((o=i.constructor)&&o.getDerivedStateFromError!=null&&(i.setState(o.getDerivedStateFromError(t)),u=i.__d),i.componentDidCatch!=null&&(i.componentDidCatch(t,r||{}),u=i.__d),u)return i.__E=i}catch(f){t=f}throw t}},$n=0,hn=function(t){return t!=null&&t.constructor==null},O.prototype.setState=function(t,n){var e;e=this.__s!=null&&this.__s!=this.state?this.__s:this.__s=C({},this.state),typeof t=="function"&&(t=t(C({},e),this.props)),t&&C(e,t),t!=null&&this.__v&&(n&&this._sb.push(n),An(this))},O.prototype.forceUpdate=function(t){this.__v&&(this.__e=!0,t&&this.__h.push(t),An(this))},O.prototype.render=$,T=[],Pn=typeof Promise=="function"?Promise.prototype.then.bind(Promise.resolve()):setTimeout,Hn=function(t,n){return t.__v.__b-n.__v.__b},z.__r=0,On=/(PointerCapture)$|Capture$/i,pn=0,an=Tn(!1),cn=Tn(!0),ht=0;var I,y,yn,Gn,Q=0,Yn=[],g=p,jn=g.__b,Jn=g.__r,Bn=g.diffed,Zn=g.__c,zn=g.unmount,Kn=g.__;function bn(t,n){g.__h&&g.__h(y,t,Q||n),Q=0;var e=y.__H||(y.__H={__:[],__h:[]});return t>=e.__.length&&e.__.push({}),e.__[t]}function Qn(t){return Q=1,St(et,t)}function St(t,n,e){var r=bn(I++,2);if(r.t=t,!r.__c&&(r.__=[e?e(n):et(void 0,n),function(f){var c=r.__N?r.__N[0]:r.__[0],s=r.t(c,f);c!==s&&(r.__N=[s,r.__[1]],r.__c.setState({}))}],r.__c=y,!y.__f)){var i=function(f,c,s){if(!r.__c.__H)return!0;var l=r.__c.__H.__.filter(function(h){return!!h.__c});if(l.every(function(h){return!h.__N}))return!o||o.call(this,f,c,s);var _=r.__c.props!==f;return l.forEach(function(h){if(h.__N){var a=h.__[0];h.__=h.__N,h.__N=void 0,a!==h.__[0]&&(_=!0)}}),o&&o.call(this,f,c,s)||_};y.__f=!0;var o=y.shouldComponentUpdate,u=y.componentWillUpdate;y.componentWillUpdate=function(f,c,s){if(this.__e){var l=o;o=void 0,i(f,c,s),o=l}u&&u.call(this,f,c,s)},y.shouldComponentUpdate=i}return r.__N||r.__}function nt(t,n){var e=bn(I++,3);!g.__s&&tt(e.__H,n)&&(e.__=t,e.u=n,y.__H.__h.push(e))}function Sn(t){return Q=5,nn(function(){return{current:t}},[])}function nn(t,n){var e=bn(I++,7);return tt(e.__H,n)&&(e.__=t(),e.__H=n,e.__h=t),e.__}function wt(){for(var t;t=Yn.shift();)if(t.__P&&t.__H)try{t.__H.__h.forEach(Y),t.__H.__h.forEach(gn),t.__H.__h=[]}catch(n){t.__H.__h=[],g.__e(n,t.__v)}}g.__b=function(t){y=null,jn&&jn(t)},g.__=function(t,n){t&&n.__k&&n.__k.__m&&(t.__m=n.__k.__m),Kn&&Kn(t,n)},g.__r=function(t){Jn&&Jn(t),I=0;var n=(y=t.__c).__H;n&&(yn===y?(n.__h=[],y.__h=[],n.__.forEach(function(e){e.__N&&(e.__=e.__N),e.u=e.__N=void 0})):(n.__h.forEach(Y),n.__h.forEach(gn),n.__h=[],I=0)),yn=y},g.diffed=function(t){Bn&&Bn(t);var n=t.__c;n&&n.__H&&(n.__H.__h.length&&(Yn.push(n)!==1&&Gn===g.requestAnimationFrame||((Gn=g.requestAnimationFrame)||kt)(wt)),n.__H.__.forEach(function(e){e.u&&(e.__H=e.u),e.u=void 0})),yn=y=null},g.__c=function(t,n){n.some(function(e){try{e.__h.forEach(Y),e.__h=e.__h.filter(function(r){return!r.__||gn(r)})}catch(r){n.some(function(i){i.__h&&(i.__h=[])}),n=[],g.__e(r,e.__v)}}),Zn&&Zn(t,n)},g.unmount=function(t){zn&&zn(t);var n,e=t.__c;e&&e.__H&&(e.__H.__.forEach(function(r){try{Y(r)}catch(i){n=i}}),e.__H=void 0,n&&g.__e(n,e.__v))};var Xn=typeof requestAnimationFrame=="function";function kt(t){var n,e=function(){clearTimeout(r),Xn&&cancelAnimationFrame(n),setTimeout(t)},r=setTimeout(e,35);Xn&&(n=requestAnimationFrame(e))}function Y(t){var n=y,e=t.__c;typeof e=="function"&&(t.__c=void 0,e()),y=n}function gn(t){var n=y;t.__c=t.__(),y=n}function tt(t,n){return!t||t.length!==n.length||n.some(function(e,r){return e!==t[r]})}function et(t,n){return typeof n=="function"?n(t):n}var xt=Symbol.for("preact-signals");function en(){if(N>1)N--;else{for(var t,n=!1;V!==void 0;){var e=V;for(V=void 0,wn++;e!==void 0;){var r=e.o;if(e.o=void 0,e.f&=-3,!(8&e.f)&&ot(e))try{e.c()}catch(i){n||(t=i,n=!0)}e=r}}if(wn=0,N--,n)throw t}}function q(t){if(N>0)return t();N++;try{return t()}finally{en()}}var v=void 0;function rn(t){var n=v;v=void 0;try{return t()}finally{v=n}}var V=void 0,N=0,wn=0,tn=0;function rt(t){if(v!==void 0){var n=t.n;if(n===void 0||n.t!==v)return n={i:0,S:t,p:v.s,n:void 0,t:v,e:void 0,x:void 0,r:n},v.s!==void 0&&(v.s.n=n),v.s=n,t.n=n,32&v.f&&t.S(n),n;if(n.i===-1)return n.i=0,n.n!==void 0&&(n.n.p=n.p,n.p!==void 0&&(n.p.n=n.n),n.p=v.s,n.n=void 0,v.s.n=n,v.s=n),n}}function b(t,n){this.v=t,this.i=0,this.n=void 0,this.t=void 0,this.W=n?.watched,this.Z=n?.unwatched,this.name=n?.name}b.prototype.brand=xt;b.prototype.h=function(){return!0};b.prototype.S=function(t){var n=this,e=this.t;e!==t&&t.e===void 0&&(t.x=e,this.t=t,e!==void 0?e.e=t:rn(function(){var r;(r=n.W)==null||r.call(n)}))};b.prototype.U=function(t){var n=this;if(this.t!==void 0){var e=t.e,r=t.x;e!==void 0&&(e.x=r,t.e=void 0),r!==void 0&&(r.e=e,t.x=void 0),t===this.t&&(this.t=r,r===void 0&&rn(function(){var i;(i=n.Z)==null||i.call(n)}))}};b.prototype.subscribe=function(t){var n=this;return A(function(){var e=n.value,r=v;v=void 0;try{t(e)}finally{v=r}},{name:"sub"})};b.prototype.valueOf=function(){return this.value};b.prototype.toString=function(){return this.value+""};b.prototype.toJSON=function(){return this.value};b.prototype.peek=function(){var t=v;v=void 0;try{return this.value}finally{v=t}};Object.defineProperty(b.prototype,"value",{get:function(){var t=rt(this);return t!==void 0&&(t.i=this.i),this.v},set:function(t){if(t!==this.v){if(wn>100)throw new Error("Cycle detected");this.v=t,this.i++,tn++,N++;try{for(var n=this.t;n!==void 0;n=n.x)n.t.N()}finally{en()}}}});function on(t,n){return new b(t,n)}function ot(t){for(var n=t.s;n!==void 0;n=n.n)if(n.S.i!==n.i||!n.S.h()||n.S.i!==n.i)return!0;return!1}function it(t){for(var n=t.s;n!==void 0;n=n.n){var e=n.S.n;if(e!==void 0&&(n.r=e),n.S.n=n,n.i=-1,n.n===void 0){t.s=n;break}}}function _t(t){for(var n=t.s,e=void 0;n!==void 0;){var r=n.p;n.i===-1?(n.S.U(n),r!==void 0&&(r.n=n.n),n.n!==void 0&&(n.n.p=r)):e=n,n.S.n=n.r,n.r!==void 0&&(n.r=void 0),n=r}t.s=e}function P(t,n){b.call(this,void 0),this.x=t,this.s=void 0,this.g=tn-1,this.f=4,this.W=n?.watched,this.Z=n?.unwatched,this.name=n?.name}P.prototype=new b;P.prototype.h=function(){if(this.f&=-3,1&this.f)return!1;if((36&this.f)==32||(this.f&=-5,this.g===tn))return!0;if(this.g=tn,this.f|=1,this.i>0&&!ot(this))return this.f&=-2,!0;var t=v;try{it(this),v=this;var n=this.x();(16&this.f||this.v!==n||this.i===0)&&(this.v=n,this.f&=-17,this.i++)}catch(e){this.v=e,this.f|=16,this.i++}return v=t,_t(this),this.f&=-2,!0};P.prototype.S=function(t){if(this.t===void 0){this.f|=36;for(var n=this.s;n!==void 0;n=n.n)n.S.S(n)}b.prototype.S.call(this,t)};P.prototype.U=function(t){if(this.t!==void 0&&(b.prototype.U.call(this,t),this.t===void 0)){this.f&=-33;for(var n=this.s;n!==void 0;n=n.n)n.S.U(n)}};P.prototype.N=function(){if(!(2&this.f)){this.f|=6;for(var t=this.t;t!==void 0;t=t.x)t.t.N()}};Object.defineProperty(P.prototype,"value",{get:function(){if(1&this.f)throw new Error("Cycle detected");var t=rt(this);if(this.h(),t!==void 0&&(t.i=this.i),16&this.f)throw this.v;return this.v}});function G(t,n){return new P(t,n)}function ut(t){var n=t.u;if(t.u=void 0,typeof n=="function"){N++;var e=v;v=void 0;try{n()}catch(r){throw t.f&=-2,t.f|=8,kn(t),r}finally{v=e,en()}}}function kn(t){for(var n=t.s;n!==void 0;n=n.n)n.S.U(n);t.x=void 0,t.s=void 0,ut(t)}function Ct(t){if(v!==this)throw new Error("Out-of-order effect");_t(this),v=t,this.f&=-2,8&this.f&&kn(this),en()}function F(t,n){this.x=t,this.u=void 0,this.s=void 0,this.o=void 0,this.f=32,this.name=n?.name}F.prototype.c=function(){var t=this.S();try{if(8&this.f||this.x===void 0)return;var n=this.x();typeof n=="function"&&(this.u=n)}finally{t()}};F.prototype.S=function(){if(1&this.f)throw new Error("Cycle detected");this.f|=1,this.f&=-9,ut(this),it(this),N++;var t=v;return v=this,Ct.bind(this,t)};F.prototype.N=function(){2&this.f||(this.f|=2,this.o=V,V=this)};F.prototype.d=function(){this.f|=8,1&this.f||kn(this)};F.prototype.dispose=function(){this.d()};function A(t,n){var e=new F(t,n);try{e.c()}catch(i){throw e.d(),i}var r=e.d.bind(e);return r[Symbol.dispose]=r,r}var Cn,un,xn,sn=typeof window<"u"&&!!window.__PREACT_SIGNALS_DEVTOOLS__,st=[],ft=[];A(function(){Cn=this.N})();function L(t,n){p[t]=n.bind(null,p[t]||function(){})}function _n(t){xn&&xn(),xn=t&&t.S()}function at(t){var n=this,e=t.data,r=ct(e);r.value=e;var i=nn(function(){for(var f=n,c=n.__v;c=c.__;)if(c.__c){c.__c.__$f|=4;break}var s=G(function(){var a=r.value.value;return a===0?0:a===!0?"":a||""}),l=G(function(){return!Array.isArray(s.value)&&!hn(s.value)}),_=A(function(){if(this.N=lt,l.value){var a=s.value;f.__v&&f.__v.__e&&f.__v.__e.nodeType===3&&(f.__v.__e.data=a)}}),h=n.__$u.d;return n.__$u.d=function(){_(),h.call(this)},[l,s]},[]),o=i[0],u=i[1];return o.value?u.peek():u.value}at.displayName="ReactiveTextNode";Object.defineProperties(b.prototype,{constructor:{configurable:!0,value:void 0},type:{configurable:!0,value:at},props:{configurable:!0,get:function(){return{data:this}}},__b:{configurable:!0,value:1}});L("__b",function(t,n){if(sn&&typeof n.type=="function"&&window.__PREACT_SIGNALS_DEVTOOLS__.exitComponent(),typeof n.type=="string"){var e,r=n.props;for(var i in r)if(i!=="children"){var o=r[i];o instanceof b&&(e||(n.__np=e={}),e[i]=o,r[i]=o.peek())}}t(n)});L("__r",function(t,n){if(sn&&typeof n.type=="function"&&window.__PREACT_SIGNALS_DEVTOOLS__.enterComponent(n),n.type!==$){_n();var e,r=n.__c;r&&(r.__$f&=-2,(e=r.__$u)===void 0&&(r.__$u=e=(function(i){var o;return A(function(){o=this}),o.c=function(){r.__$f|=1,r.setState({})},o})())),un=r,_n(e)}t(n)});L("__e",function(t,n,e,r){sn&&window.__PREACT_SIGNALS_DEVTOOLS__.exitComponent(),_n(),un=void 0,t(n,e,r)});L("diffed",function(t,n){sn&&typeof n.type=="function"&&window.__PREACT_SIGNALS_DEVTOOLS__.exitComponent(),_n(),un=void 0;var e;if(typeof n.type=="string"&&(e=n.__e)){var r=n.__np,i=n.props;if(r){var o=e.U;if(o)for(var u in o){var f=o[u];f!==void 0&&!(u in r)&&(f.d(),o[u]=void 0)}else o={},e.U=o;for(var c in r){var s=o[c],l=r[c];s===void 0?(s=Et(e,c,l,i),o[c]=s):s.o(l,i)}}}t(n)});function Et(t,n,e,r){var i=n in t&&t.ownerSVGElement===void 0,o=on(e);return{o:function(u,f){o.value=u,r=f},d:A(function(){this.N=lt;var u=o.value.value;r[n]!==u&&(r[n]=u,i?t[n]=u:u!=null&&(u!==!1||n[4]==="-")?t.setAttribute(n,u):t.removeAttribute(n))})}}L("unmount",function(t,n){if(typeof n.type=="string"){var e=n.__e;if(e){var r=e.U;if(r){e.U=void 0;for(var i in r){var o=r[i];o&&o.d()}}}}else{var u=n.__c;if(u){var f=u.__$u;f&&(u.__$u=void 0,f.d())}}t(n)});L("__h",function(t,n,e,r){(r<3||r===9)&&(n.__$f|=2),t(n,e,r)});O.prototype.shouldComponentUpdate=function(t,n){var e=this.__$u,r=e&&e.s!==void 0;for(var i in n)return!0;if(this.__f||typeof this.u=="boolean"&&this.u===!0){var o=2&this.__$f;if(!(r||o||4&this.__$f)||1&this.__$f)return!0}else if(!(r||4&this.__$f)||3&this.__$f)return!0;for(var u in t)if(u!=="__source"&&t[u]!==this.props[u])return!0;for(var f in this.props)if(!(f in t))return!0;return!1};function ct(t,n){return Qn(function(){return on(t,n)})[0]}function Nt(t,n){var e=Sn(t);return e.current=t,un.__$f|=4,nn(function(){return G(function(){return e.current()},n)},[])}var At=typeof requestAnimationFrame>"u"?setTimeout:function(t){var n=function(){clearTimeout(e),cancelAnimationFrame(r),t()},e=setTimeout(n,35),r=requestAnimationFrame(n)},Ut=function(t){queueMicrotask(function(){queueMicrotask(t)})};function Tt(){q(function(){for(var t;t=st.shift();)Cn.call(t)})}function $t(){st.push(this)===1&&(p.requestAnimationFrame||At)(Tt)}function Pt(){q(function(){for(var t;t=ft.shift();)Cn.call(t)})}function lt(){ft.push(this)===1&&(p.requestAnimationFrame||Ut)(Pt)}function Ht(t,n){var e=Sn(t);e.current=t,nt(function(){return A(function(){return this.N=$t,e.current()},n)},[])}(async()=>{let l=new URL("../../",import.meta.url).href,a=[...document.querySelectorAll("script[data-uibeam-hydrate]")].find(p=>p.src.startsWith(l)),s=a?.src??new URL("hydrate.js",l).href,d=a?.getAttribute("data-uibeam-hydrate"),h=d?`[data-uibeam-hydrater][data-uibeam-crate="${CSS.escape(d)}"]`:"[data-uibeam-hydrater]",w=a?.getAttribute("data-uibeam-wasm"),I=a?.getAttribute("data-uibeam-wasm-integrity"),m=null,g=()=>m??=import(s).then(async({default:t,...n})=>(await t(w?{module_or_path:I?fetch(w,{integrity:I}):w}:void 0),n)),y=async e=>{let n=await g(),r=e.getAttribute("data-uibeam-hydrater");if(!r){console.error(`[uibeam] no hydrater name: ${e}`);return}let i=n[r];if(!i){console.error(`[uibeam] no hydrater found for name '${r}': ${e}`);return}let o=e.getAttribute("data-uibeam-props");if(!o){console.error(`[uibeam] no props string: ${e}`);return}let u=null;try{u=JSON.parse(o)}catch(f){console.error(`[uibeam] failed to parse props JSON '${o}': ${f}`);return}if(!u){console.error(`[uibeam] no props parsed: ${e}`);return}try{i(u,e)}catch(f){let c=f instanceof Error?f.message:String(f);console.error(`[uibeam] failed to hydrate with '${r}' and props '${JSON.stringify(o)}': ${c}`)}};document.querySelectorAll(h).forEach(e=>{let r=()=>y(e);switch(e.getAttribute("data-uibeam-strategy")){case"visible":{let i=new IntersectionObserver(o=>{o.some(u=>u.isIntersecting)&&(i.disconnect(),r())});i.observe(e);break}case"idle":{"requestIdleCallback"in window?requestIdleCallback(r):setTimeout(r,200);break}case"interaction":{let i=["pointerover","pointerdown","focusin","keydown"],o=async u=>{i.forEach(f=>e.removeEventListener(f,o)),await r(),u.target.dispatchEvent(new u.constructor(u.type,u))};i.forEach(u=>e.addEventListener(u,o,{passive:!0}));break}case"media":{let i=matchMedia(e.getAttribute("data-uibeam-media")??"all");if(i.matches)r();else{let o=()=>{i.matches&&(i.removeEventListener("change",o),r())};i.addEventListener("change",o)}break}default:r()}})})();export{$ as Fragment,q as batch,Fn as createElement,vt as createRef,qn as hydrate,rn as untracked,Nt as useComputed,ct as useSignal,Ht as useSignalEffect};
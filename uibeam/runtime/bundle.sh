@@ -17,5 +17,4 @@ fi
     --bundle \
     --minify \
     --format=esm \
-    --external:'/.uibeam/hydrate.js' \
     --outfile='../runtime.mjs'
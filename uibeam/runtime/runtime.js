@@ -2,11 +2,37 @@ export { hydrate, createElement, createRef, Fragment } from 'preact';
 export { useSignal, useComputed, useSignalEffect, batch, untracked } from '@preact/signals';
 
 (async () => {
-  const { default: init, ...hydraters } = await import('/.uibeam/hydrate.js');
-  
-  await init();
-  
-  document.querySelectorAll('[data-uibeam-hydrater]').forEach((container) => {
+  // this snippet is served at `{pkg}/snippets/uibeam-{hash}/runtime.mjs`
+  const pkg = new URL('../../', import.meta.url).href;
+  // the injected `<script data-uibeam-hydrate="{crate name}">` loading this `pkg`
+  const script = [...document.querySelectorAll('script[data-uibeam-hydrate]')]
+    .find((s) => s.src.startsWith(pkg));
+  const hydrateJs = script?.src ?? new URL('hydrate.js', pkg).href;
+  // hydrate only this crate's islands if the crate is specified
+  const crateName = script?.getAttribute('data-uibeam-hydrate');
+  const containers = crateName
+    ? `[data-uibeam-hydrater][data-uibeam-crate="${CSS.escape(crateName)}"]`
+    : '[data-uibeam-hydrater]';
+
+  // fingerprinted `.wasm`, otherwise wasm-bindgen's default next to `hydrate.js`
+  const wasm = script?.getAttribute('data-uibeam-wasm');
+  // fetched with `integrity` if registered, which `init` doesn't do by itself
+  const wasmIntegrity = script?.getAttribute('data-uibeam-wasm-integrity');
+
+  // `hydrate.js` is imported and initialized once when the first island is hydrated,
+  // so that lazily hydrated islands defer the Wasm instantiation (not the downloads:
+  // `hydrate.js` is loaded by the injected `<script>` and the `.wasm` is prefetched)
+  let loading = null;
+  const load = () => loading ??= import(hydrateJs).then(async ({ default: init, ...hydraters }) => {
+    await init(wasm ? {
+      module_or_path: wasmIntegrity ? fetch(wasm, { integrity: wasmIntegrity }) : wasm,
+    } : undefined);
+    return hydraters;
+  });
+
+  const hydrateIsland = async (container) => {
+    const hydraters = await load();
+
     const hydraterName = container.getAttribute('data-uibeam-hydrater');
     if (!hydraterName) {
       console.error(`[uibeam] no hydrater name: ${container}`);
@@ -41,5 +67,58 @@ export { useSignal, useComputed, useSignalEffect, batch, untracked } from '@prea
       const message = e instanceof Error ? e.message : String(e);
       console.error(`[uibeam] failed to hydrate with '${hydraterName}' and props '${JSON.stringify(propsStr)}': ${message}`);
     }
+  };
+
+  document.querySelectorAll(containers).forEach((container) => {
+    const hydrateNow = () => hydrateIsland(container);
+
+    switch (container.getAttribute('data-uibeam-strategy')) {
+      case 'visible': {
+        const observer = new IntersectionObserver((entries) => {
+          if (entries.some((entry) => entry.isIntersecting)) {
+            observer.disconnect();
+            hydrateNow();
+          }
+        });
+        observer.observe(container);
+        break;
+      }
+      case 'idle': {
+        if ('requestIdleCallback' in window) {
+          requestIdleCallback(hydrateNow);
+        } else {
+          setTimeout(hydrateNow, 200);
+        }
+        break;
+      }
+      case 'interaction': {
+        // the triggering event is replayed to the hydrated island as a synthetic (untrusted) one
+        const events = ['pointerover', 'pointerdown', 'focusin', 'keydown'];
+        const onInteraction = async (event) => {
+          events.forEach((event) => container.removeEventListener(event, onInteraction));
+          await hydrateNow();
+          event.target.dispatchEvent(new event.constructor(event.type, event));
+        };
+        events.forEach((event) => container.addEventListener(event, onInteraction, { passive: true }));
+        break;
+      }
+      case 'media': {
+        const query = matchMedia(container.getAttribute('data-uibeam-media') ?? 'all');
+        if (query.matches) {
+          hydrateNow();
+        } else {
+          const onChange = () => {
+            if (query.matches) {
+              query.removeEventListener('change', onChange);
+              hydrateNow();
+            }
+          };
+          query.addEventListener('change', onChange);
+        }
+        break;
+      }
+      default:
+        hydrateNow();
+    }
   });
 })();
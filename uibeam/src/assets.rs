@@ -0,0 +1,579 @@
+//! Island assets served at `/.uibeam`.
+//!
+//! ## Base URL
+//!
+//! The `pkg` directory built by `wasm-pack` is expected at `/.uibeam` by default.
+//! To serve it elsewhere, e.g. under a sub-path or from a CDN, set the base URL
+//! at compile time by `UIBEAM_ASSETS_BASE` environment variable
+//! (e.g. in `[env]` of `.cargo/config.toml`):
+//!
+//! ```toml
+//! [env]
+//! UIBEAM_ASSETS_BASE = "https://cdn.example.com/app/islands"
+//! ```
+//!
+//! or, when it's known only at runtime, by [`set_base`] at startup:
+//!
+//! ```no_run
+//! uibeam::assets::set_base("https://cdn.example.com/app/islands");
+//! ```
+//!
+//! Hydration hooks injected into `<head>` and `<body>` reference the assets
+//! under this base at render time, and the runtime imports `hydrate.js`
+//! from the same URL as the injected `<script>`.
+//!
+//! ## Islands Crates
+//!
+//! By default, the single islands crate is expected to be built with
+//! `--out-name hydrate` and its `pkg` directory served at the base URL.
+//!
+//! Register islands crates by [`add_islands`] to load their assets by any
+//! out-names, each from `{base}/{crate name}`. Islands of multiple crates
+//! can coexist on a page: each crate's islands are marked by
+//! `data-uibeam-crate` and hydrated by the crate's own assets.
+//!
+//! ```no_run
+//! // `wasm-pack build` (out-name defaults to the crate name), served at `/.uibeam/my_islands`
+//! uibeam::assets::add_islands("my_islands", "my_islands");
+//! // `wasm-pack build --out-name widgets`, served at `/.uibeam/shared_widgets`
+//! uibeam::assets::add_islands("shared_widgets", "widgets");
+//! ```
+//!
+//! ## Fingerprinting
+//!
+//! For long-term caching (e.g. `Cache-Control: immutable`), the assets can be
+//! renamed with their content hashes by a build step. Register the fingerprinted
+//! names by [`set_fingerprinted`], or at startup from a manifest written by the
+//! build step by [`load_manifest`]:
+//!
+//! ```no_run
+//! # #[cfg(feature = "client")]
+//! uibeam::assets::load_manifest("./islands/pkg/manifest.json").unwrap();
+//! ```
+//!
+//! ```json
+//! {
+//!     "hydrate.js": "hydrate.3f2a9c1e.js",
+//!     "hydrate_bg.wasm": "hydrate_bg.8d41b07a.wasm"
+//! }
+//! ```
+//!
+//! The hydration hooks reference the fingerprinted names resolved at render
+//! time, and the runtime loads the fingerprinted `.wasm` file.
+//! The build step is responsible for updating the references between the
+//! renamed files, e.g. of `hydrate.js` to `snippets/uibeam-{hash}/runtime.mjs`.
+//!
+//! ## Subresource Integrity
+//!
+//! Hydration hooks injected into `<head>` and `<body>` load
+//! `runtime.mjs`, `hydrate.js` and `hydrate_bg.wasm`. When their hashes are
+//! registered, the tags get `integrity` attributes, and the runtime fetches
+//! the `.wasm` with its `integrity`, so that browsers refuse tampered assets,
+//! e.g. when they are served from a CDN.
+//!
+//! With "sri" feature, register them at startup from the built `pkg`
+//! directory by [`load_integrity`]:
+//!
+//! ```no_run
+//! # #[cfg(feature = "sri")]
+//! uibeam::assets::load_integrity("./islands/pkg").unwrap();
+//! ```
+//!
+//! or register hashes computed at build time by [`set_integrity`]:
+//!
+//! ```no_run
+//! uibeam::assets::set_integrity("hydrate.js", "sha384-oqVuAfXRKap7fdgcCY5uykM6+R9GqQ8K/uxy9rx7HNQlGYl1kPzQho1wx4JwY8wC");
+//! ```
+//!
+//! ## Process-global Configuration
+//!
+//! All the configuration here except `UIBEAM_ASSETS_BASE` is held in
+//! process-global state shared by every render, not per request or per app.
+//! Call the setters and loaders once at startup, before the first render:
+//! a render running concurrently with them may see the configuration
+//! partially applied.
+
+use crate::{AttributeValue, UI};
+use std::borrow::Cow;
+use std::sync::{LazyLock, RwLock};
+
+/// base URL where the assets are served by default
+const DEFAULT_BASE: &str = match option_env!("UIBEAM_ASSETS_BASE") {
+    Some(base) => base,
+    None => "/.uibeam",
+};
+
+static BASE: RwLock<Cow<'static, str>> = RwLock::new(Cow::Borrowed(DEFAULT_BASE));
+
+/// Sets the base URL where the `pkg` directory is served,
+/// like `"/app/.uibeam"` or `"https://cdn.example.com/islands"`.
+///
+/// A trailing `/` is ignored.
+///
+/// This overrides `UIBEAM_ASSETS_BASE` process-globally; call it once
+/// at startup, before the first render.
+pub fn set_base(base: impl Into<Cow<'static, str>>) {
+    let mut base = base.into();
+    if base.ends_with('/') {
+        base = Cow::Owned(base.trim_end_matches('/').to_owned());
+    }
+    *BASE.write().unwrap_or_else(|e| e.into_inner()) = base;
+}
+
+/// The base URL where the `pkg` directory is served, `/.uibeam` by default.
+pub fn base() -> Cow<'static, str> {
+    BASE.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// `(crate name, out-name)`s of the registered islands crates
+static ISLANDS: RwLock<Vec<(String, String)>> = RwLock::new(Vec::new());
+
+/// Registers an islands crate built by `wasm-pack` with `out_name`,
+/// whose `pkg` directory is served at `{base}/{crate_name}`.
+///
+/// `crate_name` is the name in Rust code, like `my_islands` for `my-islands`,
+/// and also `wasm-pack`'s default out-name.
+///
+/// The registration is process-global; call it at startup, before the first render.
+pub fn add_islands(crate_name: impl Into<String>, out_name: impl Into<String>) {
+    let (crate_name, out_name) = (crate_name.into(), out_name.into());
+    let mut registered = ISLANDS.write().unwrap_or_else(|e| e.into_inner());
+    match registered.iter_mut().find(|(c, _)| *c == crate_name) {
+        Some((_, o)) => *o = out_name,
+        None => registered.push((crate_name, out_name)),
+    }
+}
+
+/// `pkg` directory of an islands crate to load
+struct Pkg {
+    /// `None` for the default `pkg` served at the base URL
+    crate_name: Option<String>,
+    out_name: String,
+}
+
+impl Pkg {
+    fn all() -> Vec<Pkg> {
+        let registered = ISLANDS.read().unwrap_or_else(|e| e.into_inner());
+        if registered.is_empty() {
+            vec![Pkg {
+                crate_name: None,
+                out_name: "hydrate".into(),
+            }]
+        } else {
+            registered
+                .iter()
+                .map(|(crate_name, out_name)| Pkg {
+                    crate_name: Some(crate_name.clone()),
+                    out_name: out_name.clone(),
+                })
+                .collect()
+        }
+    }
+
+    /// path under the base URL of the asset at `path` in this `pkg` directory
+    fn path(&self, path: &str) -> String {
+        match &self.crate_name {
+            Some(crate_name) => format!("{crate_name}/{path}"),
+            None => path.to_owned(),
+        }
+    }
+
+    fn runtime_mjs(&self) -> String {
+        // this hash is fixed for uibeam's (name, version) pair
+        //
+        // ref:
+        // - https://github.com/wasm-bindgen/wasm-bindgen/blob/184509116115e23517be1cb0bccff02672acde5b/crates/macro-support/src/encode.rs#L111
+        // - https://github.com/wasm-bindgen/wasm-bindgen/blob/184509116115e23517be1cb0bccff02672acde5b/crates/macro-support/src/hash.rs
+        static UIBEAM_HASH: LazyLock<String> = LazyLock::new(|| {
+            use std::hash::{DefaultHasher, Hash, Hasher};
+
+            let mut h = DefaultHasher::new();
+            "uibeam".hash(&mut h);
+            env!("CARGO_PKG_VERSION").hash(&mut h);
+            let hash = h.finish();
+
+            let mut h = DefaultHasher::new();
+            hash.hash(&mut h);
+            0_i32.hash(&mut h);
+            let hash = h.finish();
+
+            format!("{hash:016x}")
+        });
+        self.path(&format!("snippets/uibeam-{}/runtime.mjs", *UIBEAM_HASH))
+    }
+
+    fn hydrate_js(&self) -> String {
+        self.path(&format!("{}.js", self.out_name))
+    }
+
+    fn hydrate_bg_wasm(&self) -> String {
+        self.path(&format!("{}_bg.wasm", self.out_name))
+    }
+}
+
+/// `(path under the base URL, integrity)`s
+static INTEGRITY: RwLock<Vec<(String, String)>> = RwLock::new(Vec::new());
+
+/// Registers the `integrity` of the asset at `path` under the base URL,
+/// like `"hydrate.js"`, `"snippets/uibeam-{hash}/runtime.mjs"` or
+/// `"my_islands/my_islands.js"` for an islands crate registered by [`add_islands`].
+///
+/// `integrity` is a value of `integrity` attribute like `"sha384-{base64 hash}"`.
+///
+/// The registration is process-global; call it at startup, before the first render.
+pub fn set_integrity(path: impl Into<String>, integrity: impl Into<String>) {
+    let (path, integrity) = (path.into(), integrity.into());
+    let mut registered = INTEGRITY.write().unwrap_or_else(|e| e.into_inner());
+    match registered.iter_mut().find(|(p, _)| *p == path) {
+        Some((_, i)) => *i = integrity,
+        None => registered.push((path, integrity)),
+    }
+}
+
+/// The registered `integrity` of the asset at `path` under the base URL.
+pub fn integrity(path: &str) -> Option<String> {
+    let registered = INTEGRITY.read().unwrap_or_else(|e| e.into_inner());
+    registered
+        .iter()
+        .find(|(p, _)| p == path)
+        .map(|(_, i)| i.clone())
+}
+
+/// `(path under the base URL, fingerprinted path)`s
+static FINGERPRINTED: RwLock<Vec<(String, String)>> = RwLock::new(Vec::new());
+
+/// Registers the fingerprinted name of the asset at `path` under the base URL,
+/// like `"hydrate.js"` to `"hydrate.3f2a9c1e.js"`.
+///
+/// The `integrity` of the asset is looked up by the fingerprinted one.
+///
+/// The registration is process-global; call it at startup, before the first render.
+pub fn set_fingerprinted(path: impl Into<String>, fingerprinted: impl Into<String>) {
+    let (path, fingerprinted) = (path.into(), fingerprinted.into());
+    let mut registered = FINGERPRINTED.write().unwrap_or_else(|e| e.into_inner());
+    match registered.iter_mut().find(|(p, _)| *p == path) {
+        Some((_, f)) => *f = fingerprinted,
+        None => registered.push((path, fingerprinted)),
+    }
+}
+
+/// The registered fingerprinted name of the asset at `path` under the base URL.
+pub fn fingerprinted(path: &str) -> Option<String> {
+    let registered = FINGERPRINTED.read().unwrap_or_else(|e| e.into_inner());
+    registered
+        .iter()
+        .find(|(p, _)| p == path)
+        .map(|(_, f)| f.clone())
+}
+
+/// Registers the fingerprinted names from a JSON manifest of
+/// `{ "path in the pkg directory": "fingerprinted path in the pkg directory" }`.
+#[cfg(all(feature = "client", not(hydrate)))]
+#[cfg_attr(docsrs, doc(cfg(feature = "client")))]
+pub fn load_manifest(manifest: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+    load_manifest_under("", manifest.as_ref())
+}
+
+/// [`load_manifest`] for the `pkg` directory of an islands crate registered by [`add_islands`].
+#[cfg(all(feature = "client", not(hydrate)))]
+#[cfg_attr(docsrs, doc(cfg(feature = "client")))]
+pub fn load_islands_manifest(
+    crate_name: &str,
+    manifest: impl AsRef<std::path::Path>,
+) -> std::io::Result<()> {
+    load_manifest_under(&format!("{crate_name}/"), manifest.as_ref())
+}
+
+#[cfg(all(feature = "client", not(hydrate)))]
+fn load_manifest_under(prefix: &str, manifest: &std::path::Path) -> std::io::Result<()> {
+    let manifest: std::collections::HashMap<String, String> =
+        serde_json::from_slice(&std::fs::read(manifest)?)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    for (path, fingerprinted) in manifest {
+        set_fingerprinted(
+            format!("{prefix}{path}"),
+            format!("{prefix}{fingerprinted}"),
+        );
+    }
+    Ok(())
+}
+
+/// the fingerprinted path of the asset at `path` under the base URL if registered
+fn resolve(path: String) -> String {
+    fingerprinted(&path).unwrap_or(path)
+}
+
+/// `integrity` attribute value of `sha384-{base64 hash}` for `content`.
+#[cfg(all(feature = "sri", not(hydrate)))]
+#[cfg_attr(docsrs, doc(cfg(feature = "sri")))]
+pub fn integrity_of(content: &[u8]) -> String {
+    use sha2::{Digest, Sha384};
+    format!("sha384-{}", crate::base64::encode(&Sha384::digest(content)))
+}
+
+/// Computes and registers SHA-384 `integrity` of all the JavaScript and Wasm
+/// files in the `pkg` directory built by `wasm-pack`, including
+/// `hydrate.js`, `hydrate_bg.wasm` and `snippets/uibeam-{hash}/runtime.mjs`.
+#[cfg(all(feature = "sri", not(hydrate)))]
+#[cfg_attr(docsrs, doc(cfg(feature = "sri")))]
+pub fn load_integrity(pkg_dir: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+    load_integrity_under("", pkg_dir.as_ref())
+}
+
+/// [`load_integrity`] for the `pkg` directory of an islands crate registered by [`add_islands`].
+#[cfg(all(feature = "sri", not(hydrate)))]
+#[cfg_attr(docsrs, doc(cfg(feature = "sri")))]
+pub fn load_islands_integrity(
+    crate_name: &str,
+    pkg_dir: impl AsRef<std::path::Path>,
+) -> std::io::Result<()> {
+    load_integrity_under(&format!("{crate_name}/"), pkg_dir.as_ref())
+}
+
+#[cfg(all(feature = "sri", not(hydrate)))]
+fn load_integrity_under(prefix: &str, pkg_dir: &std::path::Path) -> std::io::Result<()> {
+    fn visit(prefix: &str, root: &std::path::Path, dir: &std::path::Path) -> std::io::Result<()> {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                visit(prefix, root, &path)?;
+            } else if path
+                .extension()
+                .is_some_and(|ext| ext == "js" || ext == "mjs" || ext == "wasm")
+            {
+                let Ok(relative) = path.strip_prefix(root) else {
+                    continue;
+                };
+                let relative = relative
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                set_integrity(
+                    format!("{prefix}{relative}"),
+                    integrity_of(&std::fs::read(&path)?),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    visit(prefix, pkg_dir, pkg_dir)
+}
+
+/// URL of the asset at `path` under the base URL
+fn href(path: &str) -> String {
+    format!("{}/{path}", BASE.read().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// `integrity` attribute of the asset at `path` under the base URL:
+/// the registered one, or removing the attribute
+fn integrity_attribute(path: &str) -> AttributeValue {
+    match integrity(path) {
+        Some(integrity) => AttributeValue::Text(integrity.into()),
+        None => AttributeValue::Boolean(false),
+    }
+}
+
+/// used by `UI!` for the hydration hooks injected into `<head>`:
+/// preloads of the assets of each islands crate
+#[doc(hidden)]
+pub fn __head_hooks__() -> UI {
+    Pkg::all()
+        .into_iter()
+        .map(|pkg| {
+            let (runtime_mjs, hydrate_js, hydrate_bg_wasm) = (
+                resolve(pkg.runtime_mjs()),
+                resolve(pkg.hydrate_js()),
+                resolve(pkg.hydrate_bg_wasm()),
+            );
+            UI! {
+                <link rel="modulepreload" href={href(&runtime_mjs)} integrity={integrity_attribute(&runtime_mjs)} />
+                <link rel="modulepreload" href={href(&hydrate_js)} integrity={integrity_attribute(&hydrate_js)} />
+                <link rel="prefetch" href={href(&hydrate_bg_wasm)} as="fetch" type="application/wasm" crossorigin integrity={integrity_attribute(&hydrate_bg_wasm)}>
+            }
+        })
+        .collect()
+}
+
+/// used by `UI!` for the hydration hooks injected into `<body>`:
+/// entrypoint `<script>` of each islands crate, marked by `data-uibeam-hydrate`
+/// with the crate name for the runtime to hydrate only the crate's islands,
+/// by `data-uibeam-wasm` with the fingerprinted `.wasm` if any, and by
+/// `data-uibeam-wasm-integrity` for the runtime to fetch the `.wasm` with it
+#[doc(hidden)]
+pub fn __body_hooks__() -> UI {
+    Pkg::all()
+        .into_iter()
+        .map(|pkg| {
+            let hydrate_js = resolve(pkg.hydrate_js());
+            // tells the runtime the `.wasm` only when it's not at wasm-bindgen's default path,
+            // or when it must be fetched with `integrity`
+            let wasm = pkg.hydrate_bg_wasm();
+            let wasm_integrity = integrity_attribute(&resolve(wasm.clone()));
+            let hydrate_bg_wasm = match fingerprinted(&wasm) {
+                Some(fingerprinted) => AttributeValue::Text(href(&fingerprinted).into()),
+                None if matches!(wasm_integrity, AttributeValue::Text(_)) => {
+                    AttributeValue::Text(href(&wasm).into())
+                }
+                None => AttributeValue::Boolean(false),
+            };
+            let crate_name = match pkg.crate_name {
+                Some(crate_name) => AttributeValue::Text(crate_name.into()),
+                None => AttributeValue::Boolean(true),
+            };
+            UI! {
+                <script type="module" src={href(&hydrate_js)} integrity={integrity_attribute(&hydrate_js)} data-uibeam-hydrate={crate_name} data-uibeam-wasm={hydrate_bg_wasm} data-uibeam-wasm-integrity={wasm_integrity}></script>
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::{Mutex, MutexGuard};
+
+    /// Serializes the tests touching the global registries,
+    /// which are reset when this is acquired and dropped.
+    struct Registries(#[allow(unused)] MutexGuard<'static, ()>);
+
+    impl Registries {
+        fn acquire() -> Self {
+            static LOCK: Mutex<()> = Mutex::new(());
+            let guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+            Self::reset();
+            Self(guard)
+        }
+
+        fn reset() {
+            *BASE.write().unwrap_or_else(|e| e.into_inner()) = Cow::Borrowed(DEFAULT_BASE);
+            ISLANDS.write().unwrap_or_else(|e| e.into_inner()).clear();
+            INTEGRITY.write().unwrap_or_else(|e| e.into_inner()).clear();
+            FINGERPRINTED
+                .write()
+                .unwrap_or_else(|e| e.into_inner())
+                .clear();
+        }
+    }
+
+    impl Drop for Registries {
+        fn drop(&mut self) {
+            Self::reset();
+        }
+    }
+
+    #[test]
+    fn test_integrity() {
+        let _registries = Registries::acquire();
+
+        assert!(matches!(
+            integrity_attribute("test/a.js"),
+            AttributeValue::Boolean(false)
+        ));
+
+        set_integrity("test/a.js", "sha384-a");
+        set_integrity("test/a.js", "sha384-b");
+        assert_eq!(integrity("test/a.js").as_deref(), Some("sha384-b"));
+        assert!(matches!(
+            integrity_attribute("test/a.js"),
+            AttributeValue::Text(i) if i == "sha384-b"
+        ));
+        assert!(matches!(
+            integrity_attribute("test/b.js"),
+            AttributeValue::Boolean(false)
+        ));
+    }
+
+    #[test]
+    fn test_hooks() {
+        let _registries = Registries::acquire();
+
+        let runtime_mjs = Pkg::all()[0].runtime_mjs();
+        assert!(
+            runtime_mjs.starts_with("snippets/uibeam-") && runtime_mjs.ends_with("/runtime.mjs")
+        );
+
+        assert_eq!(
+            crate::shoot(__body_hooks__()),
+            r#"<script type="module" src="/.uibeam/hydrate.js" data-uibeam-hydrate></script>"#
+        );
+
+        set_base("https://cdn.example.com/app/islands/");
+        assert_eq!(base(), "https://cdn.example.com/app/islands");
+        add_islands("my_islands", "hydrate");
+        add_islands("my_islands", "my_islands");
+        add_islands("shared_widgets", "widgets");
+        set_integrity("shared_widgets/widgets.js", "sha384-w");
+        assert_eq!(
+            crate::shoot(__body_hooks__()),
+            concat!(
+                r#"<script type="module" src="https://cdn.example.com/app/islands/my_islands/my_islands.js" data-uibeam-hydrate="my_islands"></script>"#,
+                r#"<script type="module" src="https://cdn.example.com/app/islands/shared_widgets/widgets.js" integrity="sha384-w" data-uibeam-hydrate="shared_widgets"></script>"#,
+            )
+        );
+        assert!(crate::shoot(__head_hooks__()).contains(
+            r#"<link rel="prefetch" href="https://cdn.example.com/app/islands/shared_widgets/widgets_bg.wasm" as="fetch" type="application/wasm" crossorigin/>"#
+        ));
+        set_fingerprinted(
+            "shared_widgets/widgets.js",
+            "shared_widgets/widgets.3f2a.js",
+        );
+        set_integrity("shared_widgets/widgets.3f2a.js", "sha384-w2");
+        set_fingerprinted(
+            "shared_widgets/widgets_bg.wasm",
+            "shared_widgets/widgets_bg.8d41.wasm",
+        );
+        assert!(crate::shoot(__body_hooks__()).ends_with(
+            r#"<script type="module" src="https://cdn.example.com/app/islands/shared_widgets/widgets.3f2a.js" integrity="sha384-w2" data-uibeam-hydrate="shared_widgets" data-uibeam-wasm="https://cdn.example.com/app/islands/shared_widgets/widgets_bg.8d41.wasm"></script>"#
+        ));
+        assert!(crate::shoot(__head_hooks__()).contains(
+            r#"<link rel="prefetch" href="https://cdn.example.com/app/islands/shared_widgets/widgets_bg.8d41.wasm" as="fetch" type="application/wasm" crossorigin/>"#
+        ));
+
+        set_integrity("my_islands/my_islands_bg.wasm", "sha384-m");
+        assert!(crate::shoot(__body_hooks__()).starts_with(
+            r#"<script type="module" src="https://cdn.example.com/app/islands/my_islands/my_islands.js" data-uibeam-hydrate="my_islands" data-uibeam-wasm="https://cdn.example.com/app/islands/my_islands/my_islands_bg.wasm" data-uibeam-wasm-integrity="sha384-m"></script>"#
+        ));
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn test_load_manifest() {
+        let _registries = Registries::acquire();
+
+        /// removes the file even if the test fails
+        struct TempFile(std::path::PathBuf);
+        impl Drop for TempFile {
+            fn drop(&mut self) {
+                let _ = std::fs::remove_file(&self.0);
+            }
+        }
+
+        let manifest = TempFile(
+            std::env::temp_dir().join(format!("uibeam-test-manifest-{}.json", std::process::id())),
+        );
+        let manifest = &manifest.0;
+        std::fs::write(manifest, r#"{"manifest/a.js": "manifest/a.1234.js"}"#).unwrap();
+        load_islands_manifest("test_islands", manifest).unwrap();
+        assert_eq!(
+            fingerprinted("test_islands/manifest/a.js").as_deref(),
+            Some("test_islands/manifest/a.1234.js")
+        );
+
+        std::fs::write(manifest, "[]").unwrap();
+        assert_eq!(
+            load_manifest(manifest).unwrap_err().kind(),
+            std::io::ErrorKind::InvalidData
+        );
+    }
+
+    #[cfg(feature = "sri")]
+    #[test]
+    fn test_integrity_of() {
+        assert_eq!(
+            integrity_of(b""),
+            "sha384-OLBgp1GsljhM2TJ+sbHjaiH9txEUvgdDTAzHv2P24donTt6/529l+9Ua0vFImLlb"
+        );
+    }
+}
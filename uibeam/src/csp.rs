@@ -0,0 +1,197 @@
+//! Content-Security-Policy nonces for `<script>`s and `<style>`s.
+//!
+//! Under a strict Content-Security-Policy, every `<script>` and `<style>`
+//! needs the per-request `nonce` given in the policy. With
+//! [`shoot_with_nonce`](crate::shoot_with_nonce) or [`UI::with_nonce`],
+//! uibeam adds it to the ones written in `UI!` templates, including
+//! `@style`s and the hydration scripts of islands.
+//!
+//! Ones in raw HTML embedded by `unsafe {html}` never get it,
+//! so that injected scripts are not trusted by the policy.
+//!
+//! ```
+//! use uibeam::UI;
+//! use uibeam::csp::Nonce;
+//!
+//! let nonce = Nonce::new("r4nd0m").unwrap();
+//! let html = uibeam::shoot_with_nonce(UI! {
+//!     <script>r#"console.log('hi')"#</script>
+//! }, &nonce);
+//!
+//! assert_eq!(html, r#"<script nonce="r4nd0m">console.log('hi')</script>"#);
+//! assert_eq!(
+//!     nonce.header_value(),
+//!     "script-src 'nonce-r4nd0m' 'strict-dynamic' 'wasm-unsafe-eval'; object-src 'none'; base-uri 'none'"
+//! );
+//! ```
+
+use crate::UI;
+use std::borrow::Cow;
+
+/// # `Nonce` - per-request nonce for Content-Security-Policy
+///
+/// Generate a new one for every response, typically by [`Nonce::random`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Nonce(Cow<'static, str>);
+
+impl Nonce {
+    /// A nonce of 128 random bits from the OS, encoded in base64.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn random() -> Self {
+        let mut bytes = [0_u8; 16];
+        getrandom::fill(&mut bytes).expect("failed to get random bytes from the OS");
+        Self(Cow::Owned(crate::base64::encode(&bytes)))
+    }
+
+    /// `Some` if `nonce` is a non-empty base64 (or base64url) string
+    /// as required by Content-Security-Policy, `None` otherwise.
+    pub fn new(nonce: impl Into<Cow<'static, str>>) -> Option<Self> {
+        let nonce = nonce.into();
+        let is_valid = !nonce.is_empty()
+            && nonce
+                .trim_end_matches('=')
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'/' | b'-' | b'_'));
+        is_valid.then_some(Self(nonce))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The source expression `'nonce-{nonce}'` for a policy directive.
+    pub fn source(&self) -> String {
+        format!("'nonce-{}'", self.0)
+    }
+
+    /// A strict policy allowing only scripts with this nonce
+    /// (and scripts loaded by them):
+    ///
+    /// ```text
+    /// script-src 'nonce-{nonce}' 'strict-dynamic' 'wasm-unsafe-eval'; object-src 'none'; base-uri 'none'
+    /// ```
+    ///
+    /// `'wasm-unsafe-eval'` is for loading Wasm of islands.
+    /// `<style>`s also get the nonce, but `style-src` is not restricted
+    /// here because it would block `style` attributes. Build your own
+    /// policy with [`source`](Self::source) to restrict it.
+    pub fn header_value(&self) -> String {
+        format!(
+            "script-src {} 'strict-dynamic' 'wasm-unsafe-eval'; object-src 'none'; base-uri 'none'",
+            self.source()
+        )
+    }
+}
+
+/// # `Nonced` - `UI` with a nonce, as a response
+///
+/// Created by [`UI::with_nonce`]. Web framework integrations respond
+/// it as HTML with `nonce`s added, and with `Content-Security-Policy`
+/// header of [`Nonce::header_value`] (or the one set by [`policy`](Self::policy)).
+///
+/// ```
+/// use uibeam::UI;
+/// use uibeam::csp::Nonce;
+///
+/// fn page() -> uibeam::csp::Nonced {
+///     let nonce = Nonce::random();
+///     let policy = format!("script-src {0} 'strict-dynamic'; style-src {0}", nonce.source());
+///     UI! {
+///         <style>"p { color: red; }"</style>
+///         <p>"Hello!"</p>
+///     }
+///     .with_nonce(nonce)
+///     .policy(policy)
+/// }
+/// ```
+pub struct Nonced {
+    ui: UI,
+    nonce: Nonce,
+    policy: Option<String>,
+}
+
+impl UI {
+    /// Wraps this `UI` with `nonce` to respond under Content-Security-Policy.
+    ///
+    /// See [`Nonced`].
+    pub fn with_nonce(self, nonce: Nonce) -> Nonced {
+        Nonced {
+            ui: self,
+            nonce,
+            policy: None,
+        }
+    }
+}
+
+impl Nonced {
+    /// Replaces the value of `Content-Security-Policy` header.
+    pub fn policy(mut self, policy: impl Into<String>) -> Self {
+        self.policy = Some(policy.into());
+        self
+    }
+
+    pub fn nonce(&self) -> &Nonce {
+        &self.nonce
+    }
+
+    /// `(HTML, Content-Security-Policy)`
+    pub fn shoot(self) -> (Cow<'static, str>, String) {
+        let policy = self.policy.unwrap_or_else(|| self.nonce.header_value());
+        (crate::shoot_with_nonce(self.ui, &self.nonce), policy)
+    }
+}
+
+#[cfg(not(feature = "client"))]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_nonce() {
+        let (a, b) = (Nonce::random(), Nonce::random());
+        assert_eq!(a.as_str().len(), 24);
+        assert_ne!(a, b);
+        assert_eq!(Nonce::new(a.as_str().to_owned()), Some(a));
+
+        assert!(Nonce::new("").is_none());
+        assert!(Nonce::new("a\"b").is_none());
+        assert!(Nonce::new("a b").is_none());
+        assert!(Nonce::new("abc-_=").is_some());
+    }
+
+    #[test]
+    fn test_nonced() {
+        let (html, policy) = UI! {
+            <html>
+                <head><style>"p { color: red; }"</style></head>
+                <body><script src="/a.js"></script></body>
+            </html>
+        }
+        .with_nonce(Nonce::new("n").unwrap())
+        .shoot();
+        assert_eq!(
+            html,
+            r#"<!DOCTYPE html><html><head><style nonce="n">p { color: red; }</style></head><body><script src="/a.js" nonce="n"></script></body></html>"#
+        );
+        assert!(policy.starts_with("script-src 'nonce-n' "));
+    }
+
+    #[test]
+    fn test_nonce_only_for_templates() {
+        let injected = "<script>alert(1)</script>";
+        let analytics = UI! { <script src="/analytics.js"></script> };
+        let html = crate::shoot_with_nonce(
+            UI! {
+                <div>unsafe {injected}</div>
+                {analytics}
+                <link rel="stylesheet" href={"/a.css"}/>
+                <link rel="icon" href="/a.ico"/>
+            },
+            &Nonce::new("n").unwrap(),
+        );
+        assert_eq!(
+            html,
+            r#"<div><script>alert(1)</script></div><script src="/analytics.js" nonce="n"></script><link rel="stylesheet" href="/a.css" nonce="n"/><link rel="icon" href="/a.ico"/>"#
+        );
+    }
+}
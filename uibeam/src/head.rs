@@ -0,0 +1,372 @@
+use crate::{Beam, UI};
+
+/// # `Head` - document head management from nested Beams
+///
+/// Children of `<Head>` are not rendered in place, but hoisted into
+/// the `<head>` of the document by [`shoot`](crate::shoot).
+/// This enables any deeply nested Beam to contribute `<title>`,
+/// `<meta name="description">`, canonical links, Open Graph tags and so on,
+/// not only the layout that owns `<head>`.
+///
+/// The collected elements are deduplicated by key, and the later one
+/// in document order wins, overriding also the existing one in `<head>`:
+///
+/// - `<title>`, `<base>`
+/// - `<meta>` by its `charset`, `name`, `property` or `http-equiv`
+/// - `<link rel="canonical">`
+/// - any element having `id`
+///
+/// Other elements are deduplicated only when they are exactly the same.
+///
+/// When the output has no `<head>` (e.g. a HTML fragment), the collected
+/// elements are put at the start of the document (after `<!DOCTYPE>` or
+/// `<html>` if any) instead.
+///
+/// ## Example
+///
+/// ```
+/// use uibeam::{UI, Beam, Head};
+///
+/// struct Layout {
+///     children: UI,
+/// }
+/// impl Beam for Layout {
+///     fn render(self) -> UI {
+///         UI! {
+///             <html>
+///                 <head>
+///                     <title>"My Site"</title>
+///                 </head>
+///                 <body>
+///                     {self.children}
+///                 </body>
+///             </html>
+///         }
+///     }
+/// }
+///
+/// struct AboutPage;
+/// impl Beam for AboutPage {
+///     fn render(self) -> UI {
+///         UI! {
+///             <Head>
+///                 <title>"About | My Site"</title>
+///                 <meta name="description" content="about this site" />
+///             </Head>
+///             <h1>"About"</h1>
+///         }
+///     }
+/// }
+///
+/// let html = uibeam::shoot(UI! {
+///     <Layout>
+///         <AboutPage />
+///     </Layout>
+/// });
+/// assert!(html.contains("<title>About | My Site</title>"));
+/// assert!(!html.contains("<title>My Site</title>"));
+/// ```
+pub struct Head {
+    pub children: UI,
+}
+
+#[cfg(not(all(feature = "client", hydrate)))]
+const START: &str = "<!--uibeam:head-->";
+#[cfg(not(all(feature = "client", hydrate)))]
+const END: &str = "<!--/uibeam:head-->";
+
+impl Beam for Head {
+    fn render(self) -> UI {
+        #[cfg(not(all(feature = "client", hydrate)))]
+        {
+            // SAFETY: `children` is already a valid HTML
+            unsafe {
+                UI::new_unchecked(
+                    &[START, END],
+                    [crate::Interpolator::Children(self.children)],
+                )
+            }
+        }
+        #[cfg(all(feature = "client", hydrate))]
+        {
+            // entries have been hoisted into `<head>` on the server
+            UI::new_unchecked(crate::client::VNode::fragment(Vec::new()))
+        }
+    }
+}
+
+/// Moves all `<Head>` contents in `html` into its `<head>`.
+#[cfg(not(all(feature = "client", hydrate)))]
+pub(crate) fn hoist(html: String) -> String {
+    use uibeam_html::{Token, tokenize};
+
+    if !html.contains(START) {
+        return html;
+    }
+
+    let mut document = String::with_capacity(html.len());
+    let mut entries = Entries(Vec::new());
+    {
+        let mut rest = html.as_str();
+        while let Some(start) = rest.find(START) {
+            let content = &rest[start + START.len()..];
+            let Some(len) = content.find(END) else { break };
+            document.push_str(&rest[..start]);
+            entries.collect(&content[..len]);
+            rest = &content[len + END.len()..];
+        }
+        document.push_str(rest);
+    }
+
+    if entries.0.is_empty() {
+        return document;
+    }
+
+    // just after `<head>`, or where the entries go without `<head>`:
+    // just after `<html>` or `<!DOCTYPE>` if any, otherwise the start
+    let (mut offset, mut fallback) = (0, 0);
+    let head_start = tokenize(&document).find_map(|token| {
+        offset += token.as_str().len();
+        match token {
+            Token::StartTag(tag) if tag.name().eq_ignore_ascii_case("head") => Some(Ok(offset)),
+            Token::StartTag(tag) if tag.name().eq_ignore_ascii_case("body") => Some(Err(fallback)),
+            Token::StartTag(tag) if tag.name().eq_ignore_ascii_case("html") => {
+                fallback = offset;
+                None
+            }
+            Token::Doctype(_) => {
+                fallback = offset;
+                None
+            }
+            _ => None,
+        }
+    });
+    let head_start = match head_start.unwrap_or(Err(fallback)) {
+        Ok(head_start) => head_start,
+        Err(position) => {
+            let mut hoisted = String::with_capacity(document.len());
+            hoisted.push_str(&document[..position]);
+            for (_, entry) in &entries.0 {
+                hoisted.push_str(entry);
+            }
+            hoisted.push_str(&document[position..]);
+            return hoisted;
+        }
+    };
+
+    let (head_nodes, head_len) = top_level_nodes(&document[head_start..]);
+
+    let mut hoisted = String::with_capacity(document.len());
+    hoisted.push_str(&document[..head_start]);
+    for (range, tag) in head_nodes {
+        if tag
+            .and_then(|tag| key_of(&tag))
+            .is_some_and(|key| entries.has_key(&key))
+        {
+            continue;
+        }
+        hoisted.push_str(&document[head_start..][range]);
+    }
+    for (_, entry) in &entries.0 {
+        hoisted.push_str(entry);
+    }
+    hoisted.push_str(&document[head_start + head_len..]);
+    hoisted
+}
+
+#[cfg(not(all(feature = "client", hydrate)))]
+struct Entries<'h>(Vec<(Option<String>, &'h str)>);
+#[cfg(not(all(feature = "client", hydrate)))]
+impl<'h> Entries<'h> {
+    fn collect(&mut self, content: &'h str) {
+        for (range, tag) in top_level_nodes(content).0 {
+            let entry = &content[range];
+            if entry.trim().is_empty() {
+                continue;
+            }
+            match tag.and_then(|tag| key_of(&tag)) {
+                Some(key) => match self.0.iter_mut().find(|(k, _)| k.as_ref() == Some(&key)) {
+                    Some((_, existing)) => *existing = entry,
+                    None => self.0.push((Some(key), entry)),
+                },
+                None => {
+                    if !self.0.iter().any(|(_, e)| *e == entry) {
+                        self.0.push((None, entry));
+                    }
+                }
+            }
+        }
+    }
+
+    fn has_key(&self, key: &str) -> bool {
+        self.0.iter().any(|(k, _)| k.as_deref() == Some(key))
+    }
+}
+
+#[cfg(not(all(feature = "client", hydrate)))]
+fn key_of(tag: &uibeam_html::Tag<'_>) -> Option<String> {
+    if let Some(Some(id)) = tag.attribute("id") {
+        return Some(format!("#{id}"));
+    }
+    match &*tag.name().to_ascii_lowercase() {
+        name @ ("title" | "base") => Some(name.to_string()),
+        "meta" => {
+            if tag.attribute("charset").is_some() {
+                return Some("meta[charset]".to_string());
+            }
+            ["name", "property", "http-equiv"]
+                .into_iter()
+                .find_map(|attr| match tag.attribute(attr) {
+                    Some(Some(value)) => Some(format!("meta[{attr}={value}]")),
+                    _ => None,
+                })
+        }
+        "link" => tag
+            .attribute("rel")
+            .flatten()
+            .is_some_and(|rel| rel.eq_ignore_ascii_case("canonical"))
+            .then(|| "link[rel=canonical]".to_string()),
+        _ => None,
+    }
+}
+
+/// Splits `html` into top-level nodes with their start tags (if any)
+/// until an unmatched end tag, and returns them with the length
+/// up to the end tag.
+#[cfg(not(all(feature = "client", hydrate)))]
+#[allow(clippy::type_complexity)]
+fn top_level_nodes(
+    html: &str,
+) -> (
+    Vec<(std::ops::Range<usize>, Option<uibeam_html::Tag<'_>>)>,
+    usize,
+) {
+    use uibeam_html::{Token, is_void_element, tokenize};
+
+    let mut nodes = Vec::new();
+    let (mut pos, mut depth, mut current) = (0, 0_usize, None);
+    for token in tokenize(html) {
+        let len = token.as_str().len();
+        match token {
+            Token::StartTag(tag) => {
+                let is_empty = tag.is_self_closing() || is_void_element(tag.name());
+                match (depth, is_empty) {
+                    (0, true) => nodes.push((pos..pos + len, Some(tag))),
+                    (0, false) => {
+                        current = Some((pos, tag));
+                        depth = 1;
+                    }
+                    (_, true) => (),
+                    (_, false) => depth += 1,
+                }
+            }
+            Token::EndTag(_) if depth == 0 => return (nodes, pos),
+            Token::EndTag(_) => {
+                depth -= 1;
+                if depth == 0 {
+                    let (start, tag) = current.take().unwrap();
+                    nodes.push((start..pos + len, Some(tag)));
+                }
+            }
+            _ if depth == 0 => nodes.push((pos..pos + len, None)),
+            _ => (),
+        }
+        pos += len;
+    }
+    if let Some((start, tag)) = current {
+        nodes.push((start..pos, Some(tag)));
+    }
+    (nodes, pos)
+}
+
+#[cfg(not(feature = "client"))]
+#[cfg(test)]
+mod test {
+    use crate::{Beam, Head, UI, shoot};
+
+    struct Layout {
+        children: UI,
+    }
+    impl Beam for Layout {
+        fn render(self) -> UI {
+            UI! {
+                <html>
+                    <head>
+                        <meta charset="UTF-8">
+                        <title>"default title"</title>
+                    </head>
+                    <body>
+                        {self.children}
+                    </body>
+                </html>
+            }
+        }
+    }
+
+    struct Article {
+        title: &'static str,
+    }
+    impl Beam for Article {
+        fn render(self) -> UI {
+            UI! {
+                <Head>
+                    <title>{self.title}</title>
+                    <meta property="og:title" content={self.title} />
+                    <link rel="stylesheet" href="/article.css" />
+                </Head>
+                <article>{self.title}</article>
+            }
+        }
+    }
+
+    #[test]
+    fn test_hoist_into_head() {
+        assert_eq!(
+            shoot(UI! {
+                <Layout>
+                    <Article title="first" />
+                </Layout>
+            }),
+            r##"<!DOCTYPE html><html><head><meta charset="UTF-8"/><title>first</title><meta property="og:title" content="first"/><link rel="stylesheet" href="/article.css"/></head><body><article>first</article></body></html>"##
+        );
+    }
+
+    #[test]
+    fn test_dedup_entries() {
+        assert_eq!(
+            shoot(UI! {
+                <Layout>
+                    <Article title="first" />
+                    <Article title="second" />
+                </Layout>
+            }),
+            r##"<!DOCTYPE html><html><head><meta charset="UTF-8"/><title>second</title><meta property="og:title" content="second"/><link rel="stylesheet" href="/article.css"/></head><body><article>first</article><article>second</article></body></html>"##
+        );
+    }
+
+    #[test]
+    fn test_without_head() {
+        assert_eq!(
+            shoot(UI! {
+                <div><Article title="fragment" /></div>
+            }),
+            r##"<title>fragment</title><meta property="og:title" content="fragment"/><link rel="stylesheet" href="/article.css"/><div><article>fragment</article></div>"##
+        );
+        assert_eq!(
+            shoot(UI! {
+                <html><body><Article title="no head" /></body></html>
+            }),
+            r##"<!DOCTYPE html><html><title>no head</title><meta property="og:title" content="no head"/><link rel="stylesheet" href="/article.css"/><body><article>no head</article></body></html>"##
+        );
+    }
+
+    #[test]
+    fn test_without_entries() {
+        assert_eq!(
+            shoot(UI! {
+                <div><Head>" "</Head>"empty"</div>
+            }),
+            r##"<div>empty</div>"##
+        );
+    }
+}
@@ -1,5 +1,7 @@
 #![cfg(not(hydrate))]
 
+#[cfg(feature = "csp")]
+use crate::csp::Nonced;
 use crate::{UI, shoot};
 
 #[cfg(feature = "axum")]
@@ -18,6 +20,28 @@ impl axum_core::response::IntoResponse for UI {
     }
 }
 
+#[cfg(all(feature = "axum", feature = "csp"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "axum", feature = "csp"))))]
+impl axum_core::response::IntoResponse for Nonced {
+    #[inline]
+    fn into_response(self) -> axum_core::response::Response {
+        let (html, policy) = self.shoot();
+        let Ok(policy) = http::HeaderValue::try_from(policy) else {
+            return http::StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        };
+        axum_core::response::IntoResponse::into_response((
+            [
+                (
+                    http::header::CONTENT_TYPE,
+                    http::HeaderValue::from_static(mime::TEXT_HTML_UTF_8.as_ref()),
+                ),
+                (http::header::CONTENT_SECURITY_POLICY, policy),
+            ],
+            html,
+        ))
+    }
+}
+
 #[cfg(feature = "actix-web")]
 #[cfg_attr(docsrs, doc(cfg(feature = "actix-web")))]
 impl actix_web::Responder for UI {
@@ -29,6 +53,21 @@ impl actix_web::Responder for UI {
     }
 }
 
+#[cfg(all(feature = "actix-web", feature = "csp"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "actix-web", feature = "csp"))))]
+impl actix_web::Responder for Nonced {
+    type Body = actix_web::body::EitherBody<<actix_web::web::Html as actix_web::Responder>::Body>;
+
+    #[inline]
+    fn respond_to(self, req: &actix_web::HttpRequest) -> actix_web::HttpResponse<Self::Body> {
+        let (html, policy) = self.shoot();
+        actix_web::web::Html::new(html)
+            .customize()
+            .insert_header((actix_web::http::header::CONTENT_SECURITY_POLICY, policy))
+            .respond_to(req)
+    }
+}
+
 #[cfg(feature = "ohkami")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ohkami")))]
 impl ohkami::claw::content::IntoContent for UI {
@@ -45,3 +84,26 @@ impl ohkami::claw::content::IntoContent for UI {
         <ohkami::claw::content::Html as ohkami::claw::content::IntoContent>::openapi_responsebody()
     }
 }
+
+#[cfg(all(feature = "ohkami", feature = "csp"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "ohkami", feature = "csp"))))]
+impl ohkami::IntoResponse for Nonced {
+    #[inline]
+    fn into_response(self) -> ohkami::Response {
+        let (html, policy) = self.shoot();
+        let mut res = ohkami::Response::OK().with_html(html);
+        res.headers.set().content_security_policy(policy);
+        res
+    }
+
+    #[cfg(feature = "openapi")]
+    fn openapi_responses() -> ohkami::openapi::Responses {
+        ohkami::openapi::Responses::new([(
+            200,
+            ohkami::openapi::Response::when("OK").content(
+                <ohkami::claw::content::Html as ohkami::claw::content::IntoContent>::CONTENT_TYPE,
+                <ohkami::claw::content::Html as ohkami::claw::content::IntoContent>::openapi_responsebody(),
+            ),
+        )])
+    }
+}
@@ -38,24 +38,68 @@ compile_error!("`hydrate` cfg must be specified only when compiling to Wasm");
 /* for `UI!` use in this crate itself */
 extern crate self as uibeam;
 
+#[cfg_attr(docsrs, doc(cfg(feature = "client")))]
+#[cfg(feature = "client")]
+pub mod assets;
+#[cfg(any(feature = "csp", feature = "sri"))]
+mod base64;
 #[cfg_attr(docsrs, doc(cfg(feature = "client")))]
 #[cfg(feature = "client")]
 pub mod client;
+#[cfg_attr(docsrs, doc(cfg(feature = "csp")))]
+#[cfg(all(feature = "csp", not(all(feature = "client", hydrate))))]
+pub mod csp;
+mod head;
 #[cfg(feature = "__integration__")]
 mod integration;
+mod memo;
+#[cfg(not(all(feature = "client", hydrate)))]
+mod rope;
+mod safe;
+mod size_hint;
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+#[cfg(all(feature = "testing", not(all(feature = "client", hydrate))))]
+pub mod testing;
 
 #[cfg_attr(docsrs, doc(cfg(feature = "client")))]
 #[cfg(feature = "client")]
 pub use client::Signal;
+pub use head::Head;
+pub use memo::Memo;
+pub use safe::{SafeCss, SafeHtml, SafeUrl};
+pub use size_hint::SizeHint;
 /* macro_export client::{batch, callback, computed, effect, untracked}; */
+#[doc(hidden)]
+pub use uibeam_html::AttributeContext;
+pub use uibeam_html::Policy;
 pub use uibeam_html::escape;
 pub use uibeam_macros::UI;
 #[cfg_attr(docsrs, doc(cfg(feature = "client")))]
 #[cfg(feature = "client")]
 pub use uibeam_macros::client;
 
+#[cfg(not(all(feature = "client", hydrate)))]
+use rope::Rope;
 use std::borrow::Cow;
 
+/// used by `UI!` and `#[client]` to select their expansion for hydration
+/// or for the server by `hydrate` cfg, evaluated here in this crate
+/// not to require the cfg to be declared in every caller crate.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(all(feature = "client", hydrate))]
+macro_rules! __hydrate_or_server {
+    (items { $($hydrate:tt)* } { $($server:tt)* }) => { $($hydrate)* };
+    ($hydrate:tt $server:tt) => { $hydrate };
+}
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(all(feature = "client", hydrate)))]
+macro_rules! __hydrate_or_server {
+    (items { $($hydrate:tt)* } { $($server:tt)* }) => { $($server)* };
+    ($hydrate:tt $server:tt) => { $server };
+}
+
 #[doc(hidden)]
 #[allow(non_camel_case_types)]
 /// automatically introduced by `#[client]`
@@ -68,7 +112,7 @@ pub trait client_attribute<T> {
 /// Generated by [ `UI!` ](macro@UI), and serialized into `Cow<'static, str>` by [`shoot`] function.\
 /// See `UI!` for more details.
 pub struct UI(
-    #[cfg(not(all(feature = "client", hydrate)))] Cow<'static, str>,
+    #[cfg(not(all(feature = "client", hydrate)))] Rope,
     #[cfg(all(feature = "client", hydrate))] client::VNode,
 );
 
@@ -246,21 +290,87 @@ mod bound {
     }
 }
 
+/// Serializes `UI` into HTML string.
+///
+/// Here contents of [`Head`]s are hoisted into `<head>` of the document.
 #[cfg(not(all(feature = "client", hydrate)))]
-#[inline(always)]
+#[inline]
 pub fn shoot(ui: UI) -> Cow<'static, str> {
-    ui.0
+    match ui.0.into_str() {
+        Cow::Owned(html) => Cow::Owned(head::hoist(html)),
+        Cow::Borrowed(html) => Cow::Borrowed(html),
+    }
+}
+
+/// Serializes `UI` into HTML string like [`shoot`], indenting block elements
+/// and keeping inline content on one line, for debugging or human-readable emails.
+///
+/// See [`uibeam_html::pretty`] for the format.
+///
+/// ```
+/// use uibeam::UI;
+///
+/// assert_eq!(
+///     uibeam::shoot_pretty(UI! {
+///         <ul>
+///             <li>"Hello, "<b>"world"</b>"!"</li>
+///             <li><pre>"  as\n  it is"</pre></li>
+///         </ul>
+///     }),
+///     "<ul>\n  <li>Hello, <b>world</b>!</li>\n  <li>\n    <pre>  as\n  it is</pre>\n  </li>\n</ul>"
+/// );
+/// ```
+#[cfg(not(all(feature = "client", hydrate)))]
+pub fn shoot_pretty(ui: UI) -> Cow<'static, str> {
+    Cow::Owned(uibeam_html::pretty(&shoot(ui)))
+}
+
+/// Serializes `UI` into HTML string like [`shoot`], and adds `nonce`
+/// to every `<script>`, `<style>` and `<link>` of scripts or stylesheets
+/// written in `UI!` templates, for Content-Security-Policy.
+///
+/// Ones in raw HTML like `unsafe {html}` never get it.
+/// See [`csp`] module for the details.
+#[cfg_attr(docsrs, doc(cfg(feature = "csp")))]
+#[cfg(all(feature = "csp", not(all(feature = "client", hydrate))))]
+pub fn shoot_with_nonce(ui: UI, nonce: &csp::Nonce) -> Cow<'static, str> {
+    Cow::Owned(head::hoist(ui.0.assemble_with_nonce(nonce.as_str())))
+}
+
+/// Sanitizes untrusted HTML, like user posts, into `UI` by the allowlists of `policy`.
+///
+/// Unlike `unsafe {html}`, which embeds `html` as it is, this is safe for
+/// any input. See [`uibeam_html::sanitize`] for the details.
+///
+/// ```
+/// use uibeam::{UI, Policy};
+///
+/// let post = r#"<p>Hello, <b>world</b>!<img src=x onerror="alert(1)"></p>"#;
+/// assert_eq!(
+///     UI! { <article>{uibeam::sanitize(post, &Policy::basic_formatting())}</article> },
+///     "<article><p>Hello, <b>world</b>!</p></article>"
+/// );
+/// ```
+#[cfg(not(all(feature = "client", hydrate)))]
+pub fn sanitize(html: &str, policy: &Policy) -> UI {
+    UI(Rope::Str(Cow::Owned(uibeam_html::sanitize(html, policy))))
 }
 
 impl FromIterator<UI> for UI {
     #[cfg(not(all(feature = "client", hydrate)))]
     #[inline]
     fn from_iter<T: IntoIterator<Item = UI>>(iter: T) -> Self {
-        let mut result = String::new();
-        for item in iter {
-            result.push_str(&item.0);
+        let items = iter.into_iter().collect::<Vec<_>>();
+        let mut html = rope::Builder::with_capacity(
+            items
+                .iter()
+                .map(|item| rope::Builder::inline_len(&item.0))
+                .sum(),
+        );
+        for item in items {
+            html.push_rope(item.0);
         }
-        UI(Cow::Owned(result))
+        UI(html.finish())
     }
 
     #[cfg(all(feature = "client", hydrate))]
@@ -273,7 +383,40 @@ impl FromIterator<UI> for UI {
 
 #[cfg(not(all(feature = "client", hydrate)))]
 impl UI {
-    pub const EMPTY: UI = UI(Cow::Borrowed(""));
+    pub const EMPTY: UI = UI(Rope::EMPTY);
+
+    /// HTML of this `UI` as it is, without the post-processing of [`shoot`]
+    /// (e.g. [`Head`] contents are not yet hoisted).
+    ///
+    /// This is borrowed when the `UI` is a single static piece, and
+    /// otherwise assembled from the segments of nested `UI`s.
+    #[inline]
+    pub fn to_html(&self) -> Cow<'_, str> {
+        self.0.to_str()
+    }
+
+    /// HTML of this `UI` as it is. Same as [`to_html`](Self::to_html).
+    #[deprecated(note = "`UI` is no longer a single string; use `to_html` instead")]
+    #[inline]
+    pub fn as_str(&self) -> Cow<'_, str> {
+        self.to_html()
+    }
+
+    /// HTML of this `UI`, indented for readable diffs in tests.
+    ///
+    /// See [`uibeam_html::pretty`] for the format.
+    ///
+    /// ```
+    /// use uibeam::UI;
+    ///
+    /// assert_eq!(
+    ///     UI! { <ul><li>"a"</li><li>"b"</li></ul> }.to_pretty_string(),
+    ///     "<ul>\n  <li>a</li>\n  <li>b</li>\n</ul>"
+    /// );
+    /// ```
+    pub fn to_pretty_string(&self) -> String {
+        uibeam_html::pretty(&self.0.to_str())
+    }
 
     #[inline(always)]
     pub fn concat<const N: usize>(uis: [UI; N]) -> Self {
@@ -295,29 +438,79 @@ impl UI {
                 )
             },
             _ => {
-                let mut buf = String::with_capacity(uis.iter().map(|ui| ui.0.len()).sum());
+                let mut html = rope::Builder::with_capacity(
+                    uis.iter().map(|ui| rope::Builder::inline_len(&ui.0)).sum(),
+                );
                 for ui in uis {
-                    buf.push_str(&ui.0);
+                    html.push_rope(ui.0);
                 }
-                UI(Cow::Owned(buf))
+                UI(html.finish())
             }
         }
     }
 }
 
+#[cfg(not(all(feature = "client", hydrate)))]
+const _: () = {
+    impl Clone for UI {
+        #[inline]
+        fn clone(&self) -> Self {
+            UI(self.0.clone())
+        }
+    }
+
+    impl std::fmt::Debug for UI {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_tuple("UI").field(&self.0.to_str()).finish()
+        }
+    }
+
+    impl std::fmt::Display for UI {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.0.try_for_each(&mut |segment| f.write_str(segment))
+        }
+    }
+
+    impl PartialEq for UI {
+        #[inline]
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+    impl Eq for UI {}
+
+    impl PartialEq<str> for UI {
+        #[inline]
+        fn eq(&self, other: &str) -> bool {
+            self.0.eq_str(other)
+        }
+    }
+    impl PartialEq<&str> for UI {
+        #[inline]
+        fn eq(&self, other: &&str) -> bool {
+            self.0.eq_str(other)
+        }
+    }
+};
+
 #[doc(hidden)]
 pub enum Interpolator {
-    /// interpolation of a HTML attribute value:
+    /// interpolation of a HTML attribute value,
+    /// with the context of the attribute resolved at compile time:
     /// - `class={foo}`
     /// - `checked={true}`
     /// - `width={100}`
-    Attribute(AttributeValue),
+    Attribute(AttributeContext, AttributeValue),
     /// interpolation of HTML elements or nodes within a parent element:
     /// - `<div>{children}</div>`
     /// - `<div>{iter.map(|i| UI! { ... })}</div>`
     /// - `<div>{condition.then(|| UI! { ... })}</div>`
     /// - `<p>My name is {me.name}</p>` (in text node)
     Children(UI),
+    /// slot for CSP `nonce` just before the end of a start tag of
+    /// `<script>`, `<style>` or `<link>` written in a template:
+    /// - `<script` {nonce} `>`
+    Nonce,
 }
 
 #[doc(hidden)]
@@ -325,6 +518,45 @@ pub enum AttributeValue {
     Text(Cow<'static, str>),
     Integer(i64),
     Boolean(bool),
+    /// text trusted in the context (by `SafeUrl`, `SafeCss` or `SafeHtml`)
+    Trusted(uibeam_html::AttributeContext, Cow<'static, str>),
+}
+#[doc(hidden)]
+impl AttributeValue {
+    /// used by `UI!` to add the scope class of `@style`s
+    /// to interpolated `class` attributes
+    pub fn with_class(self, scope: &'static str) -> Self {
+        match self {
+            AttributeValue::Text(text) | AttributeValue::Trusted(_, text) if text.is_empty() => {
+                AttributeValue::Text(scope.into())
+            }
+            AttributeValue::Text(text) | AttributeValue::Trusted(_, text) => {
+                AttributeValue::Text(format!("{text} {scope}").into())
+            }
+            AttributeValue::Integer(int) => AttributeValue::Text(format!("{int} {scope}").into()),
+            AttributeValue::Boolean(_) => AttributeValue::Text(scope.into()),
+        }
+    }
+
+    /// used by `UI!` to sanitize interpolated attribute values
+    /// for the `context` of the attribute in hydration
+    /// (on the server, this is done in `UI::new_unchecked`)
+    pub fn sanitize(self, context: AttributeContext) -> Self {
+        match self {
+            AttributeValue::Text(text) => {
+                let sanitized = match context.sanitize(&text) {
+                    Cow::Borrowed(s) if std::ptr::eq(s, &*text) => None,
+                    s => Some(s.into_owned()),
+                };
+                AttributeValue::Text(sanitized.map_or(text, Cow::Owned))
+            }
+            AttributeValue::Trusted(trusted, text) if safe::is_trusted_in(trusted, context) => {
+                AttributeValue::Text(text)
+            }
+            AttributeValue::Trusted(_, text) => AttributeValue::Text(text).sanitize(context),
+            other => other,
+        }
+    }
 }
 #[cfg(all(feature = "client", hydrate))]
 impl From<AttributeValue> for wasm_bindgen::JsValue {
@@ -332,13 +564,15 @@ impl From<AttributeValue> for wasm_bindgen::JsValue {
         match value {
             AttributeValue::Integer(int) => int.into(),
             AttributeValue::Boolean(boo) => boo.into(),
-            AttributeValue::Text(text) => match uibeam_html::escape(&text) {
-                Cow::Owned(escaped) => escaped.into(),
-                Cow::Borrowed(_) => match text {
-                    Cow::Owned(s) => s.into(),
-                    Cow::Borrowed(s) => s.into(),
-                },
-            },
+            AttributeValue::Text(text) | AttributeValue::Trusted(_, text) => {
+                match uibeam_html::escape(&text) {
+                    Cow::Owned(escaped) => escaped.into(),
+                    Cow::Borrowed(_) => match text {
+                        Cow::Owned(s) => s.into(),
+                        Cow::Borrowed(s) => s.into(),
+                    },
+                }
+            }
         }
     }
 }
@@ -439,6 +673,56 @@ const _: () = {
 pub trait IntoChildren<T, const ESCAPE: bool = true> {
     fn into_children(self) -> UI;
 }
+
+/// `{expression}` as children in `UI!`, resolved to:
+///
+/// 1. the inherent `into_children` when `expression` is `UI` or `&UI`,
+///    or an integer or `bool` (`SafeChildren`)
+/// 2. otherwise `IntoChildren` via `IntoChildrenFallback`
+///
+/// This is needed because `UI` and `SafeChildren`s are also `Display`,
+/// so `IntoChildren::<_, ESCAPE>::into_children(ui)` is ambiguous.
+#[doc(hidden)]
+pub struct Children<T, const ESCAPE: bool>(pub T);
+impl<const ESCAPE: bool> Children<UI, ESCAPE> {
+    #[inline(always)]
+    pub fn into_children(self) -> UI {
+        self.0
+    }
+}
+/// `{&STATIC_UI}`, not escaped via `Display`
+#[cfg(not(all(feature = "client", hydrate)))]
+impl<const ESCAPE: bool> Children<&UI, ESCAPE> {
+    #[inline(always)]
+    pub fn into_children(self) -> UI {
+        self.0.clone()
+    }
+}
+impl<T: SafeChildren, const ESCAPE: bool> Children<T, ESCAPE> {
+    #[inline(always)]
+    pub fn into_children(self) -> UI {
+        self.0.into_safe_children()
+    }
+}
+#[doc(hidden)]
+pub trait IntoChildrenFallback<T> {
+    fn into_children(self) -> UI;
+}
+impl<T, C: IntoChildren<T, ESCAPE>, const ESCAPE: bool> IntoChildrenFallback<T>
+    for Children<C, ESCAPE>
+{
+    #[inline(always)]
+    fn into_children(self) -> UI {
+        IntoChildren::<T, ESCAPE>::into_children(self.0)
+    }
+}
+
+/// children known to need no escaping, like integers, rendered
+/// without `Display` formatting or escaping
+#[doc(hidden)]
+pub trait SafeChildren {
+    fn into_safe_children(self) -> UI;
+}
 const _: () = {
     impl<const ESCAPE: bool> IntoChildren<UI, ESCAPE> for UI {
         fn into_children(self) -> UI {
@@ -457,7 +741,42 @@ const _: () = {
         }
     }
 
+    // sibling subtrees rendered on the rayon thread pool, and concatenated in order
+    #[cfg(all(feature = "rayon", not(all(feature = "client", hydrate))))]
+    impl<const ESCAPE: bool, P> IntoChildren<((), P), ESCAPE> for P
+    where
+        P: rayon::iter::ParallelIterator<Item = UI>,
+    {
+        fn into_children(self) -> UI {
+            UI::from_iter(self.collect::<Vec<_>>())
+        }
+    }
+
     impl<const ESCAPE: bool, D: std::fmt::Display> IntoChildren<&dyn std::fmt::Display, ESCAPE> for D {
+        #[cfg(not(all(feature = "client", hydrate)))]
+        fn into_children(self) -> UI {
+            use std::fmt::Write;
+
+            /// escapes every `str` written through it
+            struct Escaping<'w>(&'w mut rope::Text);
+            impl Write for Escaping<'_> {
+                #[inline]
+                fn write_str(&mut self, s: &str) -> std::fmt::Result {
+                    uibeam_html::escape_into(self.0, s)
+                }
+            }
+
+            // written directly into the inline buffer of `text`, or just one `String`
+            // for a long text, without another `String` for escaping
+            let mut text = rope::Text::new();
+            let _ = match ESCAPE {
+                true => write!(Escaping(&mut text), "{self}"),
+                false => write!(text, "{self}"),
+            };
+            UI(text.into_rope())
+        }
+
+        #[cfg(all(feature = "client", hydrate))]
         fn into_children(self) -> UI {
             let text = self.to_string();
 
@@ -472,8 +791,39 @@ const _: () = {
                 text
             };
 
+            UI(client::VNode::text(text))
+        }
+    }
+
+    macro_rules! integers {
+        ($($int:ty as $cast:ty => $inline:ident),*) => {$(
+            impl SafeChildren for $int {
+                #[inline]
+                fn into_safe_children(self) -> UI {
+                    #[cfg(not(all(feature = "client", hydrate)))]
+                    return UI(Rope::Inline(rope::Inline::$inline(self as $cast)));
+
+                    #[cfg(all(feature = "client", hydrate))]
+                    return UI(client::VNode::text(self.to_string()));
+                }
+            }
+        )*};
+    }
+    integers!(
+        i8 as i64 => int, i16 as i64 => int, i32 as i64 => int, i64 as i64 => int, isize as i64 => int,
+        u8 as u64 => uint, u16 as u64 => uint, u32 as u64 => uint, u64 as u64 => uint, usize as u64 => uint
+    );
+
+    impl SafeChildren for bool {
+        #[inline]
+        fn into_safe_children(self) -> UI {
+            let text = match self {
+                true => "true",
+                false => "false",
+            };
+
             #[cfg(not(all(feature = "client", hydrate)))]
-            return UI(Cow::Owned(text));
+            return UI(Rope::from_static(text));
 
             #[cfg(all(feature = "client", hydrate))]
             return UI(client::VNode::text(text));
@@ -492,6 +842,17 @@ impl UI {
         self.0
     }
 
+    #[cfg(not(all(feature = "client", hydrate)))]
+    /// used by the `UI!` macro for a template without interpolations,
+    /// as a `const` item.
+    ///
+    /// ## SAFETY
+    ///
+    /// `html` must be a valid, already HTML-escaped HTML string.
+    pub const unsafe fn new_static(html: &'static str) -> Self {
+        Self(Rope::from_static(html))
+    }
+
     #[cfg(not(all(feature = "client", hydrate)))]
     /// tends to be used by the `UI!` macro internally.
     ///
@@ -506,6 +867,24 @@ impl UI {
     pub unsafe fn new_unchecked<const N: usize>(
         template_pieces: &'static [&'static str],
         interpolators: [Interpolator; N],
+    ) -> Self {
+        let static_len = template_pieces.iter().map(|piece| piece.len()).sum();
+        unsafe { Self::new_unchecked_sized(static_len, template_pieces, interpolators) }
+    }
+
+    #[cfg(not(all(feature = "client", hydrate)))]
+    /// `new_unchecked` with `static_len`, the total length of `template_pieces`
+    /// computed by the `UI!` macro at compile time.
+    ///
+    /// ## SAFETY
+    ///
+    /// In addition to the ones of `new_unchecked`, `static_len` must be
+    /// the total length of `template_pieces`. (otherwise this only causes
+    /// reallocation or overallocation, but is not intended)
+    pub unsafe fn new_unchecked_sized<const N: usize>(
+        static_len: usize,
+        template_pieces: &'static [&'static str],
+        interpolators: [Interpolator; N],
     ) -> Self {
         #[cfg(debug_assertions)]
         {
@@ -516,24 +895,44 @@ impl UI {
             );
         }
 
+        if N == 1 && static_len == 0 {
+            // just `{children}`, e.g. passing `children` to a Beam
+            // (not an attribute value because the piece has no `=`)
+            if let Some(Interpolator::Children(children)) = interpolators.into_iter().next() {
+                return children;
+            }
+            unreachable!()
+        }
+
         match template_pieces.len() {
             0 => UI::EMPTY,
-            1 => UI(Cow::Borrowed(template_pieces[0])),
+            1 => UI(Rope::from_static(template_pieces[0])),
             _ => {
-                let mut buf = String::with_capacity({
-                    let mut size = 0;
-                    for piece in template_pieces {
-                        size += piece.len();
-                    }
+                #[cfg(debug_assertions)]
+                {
+                    let len = template_pieces
+                        .iter()
+                        .map(|piece| piece.len())
+                        .sum::<usize>();
+                    assert_eq!(static_len, len, "invalid static_len");
+                }
+
+                // short pieces are copied into the buffer of `html`, and long ones
+                // including nested `UI`s are moved into it as segments without copying
+                let mut html = rope::Builder::with_capacity({
+                    let mut size = static_len;
                     for expression in &interpolators {
                         size += match expression {
-                            Interpolator::Children(children) => children.0.len(),
-                            Interpolator::Attribute(value) => match value {
-                                AttributeValue::Text(text) => {
-                                    1/* " */ + text.len() + 1 /* " */
+                            Interpolator::Children(children) => {
+                                rope::Builder::inline_len(&children.0)
+                            }
+                            Interpolator::Nonce => 0,
+                            Interpolator::Attribute(_, value) => match value {
+                                AttributeValue::Text(text) | AttributeValue::Trusted(_, text) => {
+                                    1/* " */ + text.size_hint() + 1 /* " */
                                 }
-                                AttributeValue::Integer(_) => {
-                                    1/* " */ + 4/* max-class length of typically used integer attribute values */ + 1 /* " */
+                                AttributeValue::Integer(int) => {
+                                    1/* " */ + int.size_hint() + 1 /* " */
                                 }
                                 AttributeValue::Boolean(_) => {
                                     0 /* not push any tokens */
@@ -544,13 +943,18 @@ impl UI {
                     size
                 });
 
-                for i in 0..N {
-                    buf.push_str(template_pieces[i]);
-                    match &interpolators[i] {
+                for (i, expression) in interpolators.into_iter().enumerate() {
+                    let piece = template_pieces[i];
+                    match expression {
                         Interpolator::Children(children) => {
-                            buf.push_str(&children.0);
+                            html.push_str(piece);
+                            html.push_rope(children.0);
                         }
-                        Interpolator::Attribute(value) => {
+                        Interpolator::Nonce => {
+                            html.push_str(piece);
+                            html.push_nonce();
+                        }
+                        Interpolator::Attribute(context, value) => {
                             #[cfg(debug_assertions)]
                             {
                                 // expect like
@@ -560,19 +964,42 @@ impl UI {
                                 //            |
                                 //            /-- this `value` is here
                                 // ```
-                                assert!(buf.ends_with('='));
+                                assert!(piece.ends_with('='));
                             }
+                            let trusted_context = match &value {
+                                AttributeValue::Trusted(context, _) => Some(*context),
+                                _ => None,
+                            };
                             match value {
-                                AttributeValue::Text(text) => {
-                                    buf.push('"');
-                                    buf.push_str(&escape(text));
-                                    buf.push('"');
+                                AttributeValue::Text(text) | AttributeValue::Trusted(_, text) => {
+                                    let is_trusted = trusted_context.is_some_and(|trusted| {
+                                        safe::is_trusted_in(trusted, context)
+                                    });
+                                    let text = match is_trusted {
+                                        true => text,
+                                        false => match context.sanitize(&text) {
+                                            Cow::Borrowed(s) if std::ptr::eq(s, &*text) => text,
+                                            sanitized => Cow::Owned(sanitized.into_owned()),
+                                        },
+                                    };
+                                    let text = match escape(&text) {
+                                        // this means `text` needs no escaping, so we can avoid
+                                        // allocation, just using `text` directly
+                                        Cow::Borrowed(_) => text,
+                                        Cow::Owned(escaped) => Cow::Owned(escaped),
+                                    };
+                                    html.push_str(piece);
+                                    html.push_str("\"");
+                                    html.push_cow(text);
+                                    html.push_str("\"");
                                 }
                                 AttributeValue::Integer(int) => {
-                                    // here we don't need to escape
-                                    buf.push('"');
-                                    buf.push_str(&int.to_string());
-                                    buf.push('"');
+                                    // here we don't need to escape, and the digits
+                                    // are written without allocating a `String`
+                                    html.push_str(piece);
+                                    html.push_str("\"");
+                                    html.push_inline(rope::Inline::int(int));
+                                    html.push_str("\"");
                                 }
                                 AttributeValue::Boolean(boolean) => {
                                     // if `boolean` is `true`, we'll just leave the attribute name :
@@ -595,25 +1022,33 @@ impl UI {
                                     // <input type="checkbox"
                                     // ```
                                     //
-                                    // this can be done by removing after the last whitespace of current `buf`
-                                    // (because the SAFETY contract encusres `buf` is a part of a valid HTML string
-                                    // and then at least one whitespace exists before an attribute name)
-                                    let Some('=') = buf.pop() else { unreachable!() };
-                                    if !*boolean {
-                                        let Some(sp) = buf.rfind(|c| {
-                                            matches!(c, ' ' | '\t' | '\n' | '\x0C' | '\r')
-                                        }) else {
-                                            unreachable!()
-                                        };
-                                        buf.truncate(sp);
-                                    }
+                                    // this can be done by removing after the last whitespace of current `piece`
+                                    // (because the SAFETY contract encusres `piece` is a part of a valid HTML string
+                                    // just after a start tag name or another attribute, and then at least one
+                                    // whitespace exists before an attribute name)
+                                    let Some(name_end) = piece.strip_suffix('=') else {
+                                        unreachable!()
+                                    };
+                                    let end = match boolean {
+                                        true => name_end.len(),
+                                        false => {
+                                            let Some(sp) = name_end.rfind(|c| {
+                                                matches!(c, ' ' | '\t' | '\n' | '\x0C' | '\r')
+                                            }) else {
+                                                unreachable!()
+                                            };
+                                            sp
+                                        }
+                                    };
+                                    html.push_str(&piece[..end]);
                                 }
                             }
                         }
                     }
                 }
-                buf.push_str(template_pieces[N]);
-                UI(Cow::Owned(buf))
+                html.push_str(template_pieces[N]);
+
+                UI(html.finish())
             }
         }
     }
@@ -632,7 +1067,7 @@ mod test {
             todo!()
         }
 
-        is_children(dummy_ui());
+        is_children::<UI, _>(dummy_ui());
         is_children(Some(dummy_ui()));
         is_children(None::<UI>);
         is_children((1..=3).map(|_| dummy_ui()));
@@ -651,7 +1086,10 @@ mod test {
             (unsafe {
                 UI::new_unchecked(
                     &[r##"<div class="##, r##"></div>"##],
-                    [Interpolator::Attribute(AttributeValue::from("foo"))],
+                    [Interpolator::Attribute(
+                        AttributeContext::Text,
+                        AttributeValue::from("foo"),
+                    )],
                 )
             })
             .0,
@@ -663,7 +1101,10 @@ mod test {
                 UI::new_unchecked(
                     &[r##"<article class="##, r##">"##, r##"</article>"##],
                     [
-                        Interpolator::Attribute(AttributeValue::from("main-article")),
+                        Interpolator::Attribute(
+                            AttributeContext::Text,
+                            AttributeValue::from("main-article"),
+                        ),
                         Interpolator::Children(IntoChildren::<_, true>::into_children(
                             (1..=3_usize).map(|i| {
                                 UI::new_unchecked(
@@ -753,4 +1194,262 @@ mod test {
             r##"<!DOCTYPE html><html><head><meta charset="UTF-8"/></head><body><h1>Hello, Beam!</h1>[test]</body></html>"##
         );
     }
+
+    #[test]
+    fn test_ui_interpolate_primitives() {
+        let ui = UI! {
+            <p data-min={i64::MIN} data-max={u64::MAX as i64} data-zero={0}>
+                {42}" "{-7_i8}" "{u64::MAX}" "{0_usize}" "{true}" "{'<'}" "{1.5}
+            </p>
+        };
+        assert_eq!(
+            shoot(ui),
+            r##"<p data-min="-9223372036854775808" data-max="-1" data-zero="0">42 -7 18446744073709551615 0 true &lt; 1.5</p>"##
+        );
+        // rendered as `SafeChildren`, not via `Display`
+        assert!(matches!(
+            UI! { {false} }.0,
+            Rope::Str(Cow::Borrowed("false"))
+        ));
+
+        struct Quote(&'static str);
+        impl std::fmt::Display for Quote {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "<q>{}</q>", self.0)
+            }
+        }
+        for text in ["short", "longer than the inline capacity & escaped"] {
+            assert_eq!(
+                shoot(UI! { <div>{Quote(text)}</div> }),
+                format!("<div>&lt;q&gt;{}&lt;/q&gt;</div>", escape(text))
+            );
+            assert_eq!(
+                shoot(UI! { <div>unsafe {Quote(text)}</div> }),
+                format!("<div><q>{text}</q></div>")
+            );
+        }
+    }
+
+    #[test]
+    fn test_ui_scoped_style() {
+        struct Card {
+            class: &'static str,
+            children: UI,
+        }
+        impl Beam for Card {
+            fn render(self) -> UI {
+                UI! {
+                    @style r#"
+                        .card { padding: 1rem; }
+                        .card > p::first-line { font-weight: bold; }
+                    "#;
+                    <div class="card">
+                        <p class={self.class}>{self.children}</p>
+                        <hr>
+                    </div>
+                }
+            }
+        }
+
+        let html = shoot(UI! {
+            <html>
+                <head></head>
+                <body>
+                    <Card class="first">"1"</Card>
+                    <Card class="">"2"</Card>
+                </body>
+            </html>
+        });
+
+        let scope = &html[html.find("ub-").unwrap()..][.."ub-".len() + 8];
+        assert_eq!(
+            html,
+            format!(
+                r##"<!DOCTYPE html><html><head><style data-uibeam-scope="{scope}">.card.{scope}{{padding: 1rem;}}.card > p.{scope}::first-line{{font-weight: bold;}}</style></head><body><div class="card {scope}"><p class="first {scope}">1</p><hr class="{scope}"/></div><div class="card {scope}"><p class="{scope}">2</p><hr class="{scope}"/></div></body></html>"##
+            )
+        );
+    }
+
+    #[test]
+    fn test_ui_minify() {
+        struct Code {
+            children: UI,
+        }
+        impl Beam for Code {
+            fn render(self) -> UI {
+                UI! { <code>{self.children}</code> }
+            }
+        }
+
+        let name = "a  b";
+        let html = shoot(UI! {
+            @minify;
+            <div title="  kept  ">
+                "
+                    Hello,\t\t"{name}"!
+                "
+                {"  interpolated \n literal  "}
+                unsafe {"  raw  "}
+                <Code>"  in   a Beam  "</Code>
+                <pre>"  1\n\n  2"<Code>"  in   pre  "</Code></pre>
+                <textarea>"  a\n  b"</textarea>
+                <script>r#"
+                    if (a  <  b) {}
+                "#</script>
+                <p>r#"  "raw"  string  "#</p>
+            </div>
+        });
+        assert_eq!(
+            html,
+            concat!(
+                r#"<div title="  kept  "> Hello, a  b! "#,
+                r#"interpolated literal   raw  <code> in a Beam </code>"#,
+                "<pre>  1\n\n  2<code>  in   pre  </code></pre>",
+                "<textarea>  a\n  b</textarea>",
+                "<script>\n                    if (a  <  b) {}\n                </script>",
+                r#"<p> "raw" string </p></div>"#,
+            )
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_ui_parallel_children() {
+        use rayon::prelude::*;
+
+        let ui = UI! {
+            <ol>
+                {(0..100).into_par_iter().map(|i| UI! {
+                    <li>{i}</li>
+                })}
+            </ol>
+        };
+        let expected = (0..100)
+            .map(|i| format!("<li>{i}</li>"))
+            .collect::<String>();
+        assert_eq!(shoot(ui), format!("<ol>{expected}</ol>"));
+    }
+
+    #[test]
+    fn test_ui_static() {
+        const NAV: UI = UI! {
+            <nav><a href="/">"home"</a></nav>
+            <hr>
+        };
+        assert!(matches!(NAV.0, Rope::Str(Cow::Borrowed(_))));
+        assert_eq!(shoot(NAV), r##"<nav><a href="/">home</a></nav><hr/>"##);
+
+        static FOOTER: UI = UI! {
+            <footer>"static"</footer>
+        };
+        assert_eq!(
+            shoot(UI! { <body>{NAV}{&FOOTER}</body> }),
+            r##"<body><nav><a href="/">home</a></nav><hr/><footer>static</footer></body>"##
+        );
+
+        let ui = UI! {
+            <html><body>"static"</body></html>
+        };
+        assert!(matches!(
+            shoot(ui),
+            Cow::Borrowed("<!DOCTYPE html><html><body>static</body></html>")
+        ));
+    }
+
+    #[test]
+    fn test_ui_single_allocation() {
+        let name = "uibeam";
+        let items = (1..=3).map(|i| UI! { <li data-i={i * 100}>{i}</li> });
+        let ui = UI! {
+            <div data-n={-12345} data-m={7_u8} title={name}>
+                <p>"Hello, "{name}"!"</p>
+                <ul>{items}</ul>
+            </div>
+        };
+
+        // assembled from the segments just once
+        let Cow::Owned(html) = shoot(ui) else {
+            panic!("expected owned HTML")
+        };
+        assert_eq!(html.capacity(), html.len(), "{html}");
+    }
+
+    #[test]
+    fn test_ui_inspection() {
+        let name = "uibeam";
+        let ui = UI! { <div class="greeting"><p>"Hello, "{name}"!"</p><br></div> };
+
+        #[allow(deprecated)]
+        {
+            assert_eq!(
+                ui.as_str(),
+                r##"<div class="greeting"><p>Hello, uibeam!</p><br/></div>"##
+            );
+            assert_eq!(ui.to_string(), ui.as_str());
+        }
+        assert_eq!(
+            format!("{ui:?}"),
+            r##"UI("<div class=\"greeting\"><p>Hello, uibeam!</p><br/></div>")"##
+        );
+        assert_eq!(ui.clone(), ui);
+        assert_eq!(
+            UI! { <p>"Hello, "{name}"!"</p> },
+            r##"<p>Hello, uibeam!</p>"##
+        );
+        assert_ne!(UI! { <p>"a"</p> }, UI! { <p>"b"</p> });
+        assert_eq!(
+            ui.to_pretty_string(),
+            "<div class=\"greeting\">\n  <p>Hello, uibeam!</p>\n  <br/>\n</div>"
+        );
+    }
+
+    #[test]
+    fn test_ui_url_attributes() {
+        let (safe, dangerous) = ("/search?q=a&b", " JavaScript:alert(1)");
+
+        assert_eq!(
+            UI! {
+                <a href={safe}>"safe"</a>
+                <a href={dangerous} title={dangerous}>"dangerous"</a>
+                <form action={dangerous}><button formaction={dangerous}>"submit"</button></form>
+                <img src={dangerous} srcset={format!("/a.png 1x, {} 2x", dangerous.trim())} />
+                <a href={SafeUrl::trusted("javascript:void(0)")}>"trusted"</a>
+                <a href="javascript:void(0)">"literal"</a>
+                <object data={dangerous}></object>
+                <table background={dangerous}></table>
+                <a ping={dangerous}>"ping"</a>
+            },
+            r##"<a href="/search?q=a&amp;b">safe</a><a href="about:invalid#uibeam" title=" JavaScript:alert(1)">dangerous</a><form action="about:invalid#uibeam"><button formaction="about:invalid#uibeam">submit</button></form><img src="about:invalid#uibeam" srcset="/a.png 1x, about:invalid#uibeam 2x"/><a href="javascript:void(0)">trusted</a><a href="javascript:void(0)">literal</a><object data="about:invalid#uibeam"></object><table background="about:invalid#uibeam"></table><a ping="about:invalid#uibeam">ping</a>"##
+        );
+
+        assert_eq!(
+            SafeUrl::new("https://example.com").unwrap().as_str(),
+            "https://example.com"
+        );
+        assert_eq!(SafeUrl::new("javascript:alert(1)"), None);
+    }
+
+    #[test]
+    fn test_ui_attribute_contexts() {
+        let (css, dangerous_css) = ("color: red", "width: expression(alert(1))");
+        let html = "<b>bold</b>";
+
+        assert_eq!(
+            UI! {
+                <p style={css}>"safe"</p>
+                <p style={dangerous_css} title={dangerous_css}>"dangerous"</p>
+                <p style={SafeCss::trusted("behavior: url(a.htc)")}>"trusted"</p>
+                <p style={SafeUrl::trusted("expression(alert(1))")}>"trusted in other context"</p>
+                <iframe srcdoc={html}></iframe>
+                <iframe srcdoc={SafeHtml::from(UI! { <b>{html}</b> })}></iframe>
+            },
+            r##"<p style="color: red">safe</p><p style="/*invalid#uibeam*/" title="width: expression(alert(1))">dangerous</p><p style="behavior: url(a.htc)">trusted</p><p style="/*invalid#uibeam*/">trusted in other context</p><iframe srcdoc="&amp;lt;b&amp;gt;bold&amp;lt;/b&amp;gt;"></iframe><iframe srcdoc="&lt;b&gt;&amp;lt;b&amp;gt;bold&amp;lt;/b&amp;gt;&lt;/b&gt;"></iframe>"##
+        );
+
+        // every `on*` attribute is an event handler, even one unknown to uibeam
+        assert_eq!(
+            AttributeContext::of("onafterscriptexecute").sanitize("alert(1)"),
+            ""
+        );
+    }
 }
@@ -0,0 +1,201 @@
+use crate::{Beam, IntoChildren, UI};
+use std::hash::Hash;
+
+/// # `Memo` - caching rendered HTML of a Beam
+///
+/// `Memo(beam)` renders `beam` just once for the same props, and reuses
+/// the cached HTML for later renderings without copying it.
+/// The cache is looked up by the type and the `Hash` of `beam`,
+/// and a hit is confirmed by comparing the props with `Eq`,
+/// so hash collisions never return another props' HTML.
+///
+/// This is for Beams rendering the same HTML on every request,
+/// like navbars or footers. The cache lives for the whole process
+/// and holds at most [`Memo::CAPACITY`] entries: once it's full,
+/// Beams with new props are just rendered without being cached.
+///
+/// `Memo(beam)` is interpolated as children of `UI!` like `{Memo(beam)}`.
+///
+/// On the client side (in hydration), this just renders `beam`.
+///
+/// ## Example
+///
+/// ```
+/// use uibeam::{Beam, Memo, UI};
+///
+/// #[derive(Hash, PartialEq, Eq, Clone)]
+/// struct Navbar {
+///     active: &'static str,
+/// }
+/// impl Beam for Navbar {
+///     fn render(self) -> UI {
+///         UI! {
+///             <nav>
+///                 {["home", "about"].map(|page| UI! {
+///                     <a href={format!("/{page}")} data-active={page == self.active}>{page}</a>
+///                 })}
+///             </nav>
+///         }
+///     }
+/// }
+///
+/// let page = UI! {
+///     <header>{Memo(Navbar { active: "home" })}</header>
+///     <main>"..."</main>
+/// };
+/// assert!(uibeam::shoot(page).starts_with(r#"<header><nav><a href="/home" data-active>home</a>"#));
+/// ```
+pub struct Memo<B>(pub B);
+
+impl Memo<()> {
+    /// Maximum number of entries the cache of `Memo` holds.
+    pub const CAPACITY: usize = 1024;
+}
+
+#[cfg(not(all(feature = "client", hydrate)))]
+mod cache {
+    use crate::rope::Rope;
+    use std::any::{Any, TypeId};
+    use std::collections::HashMap;
+    use std::hash::{BuildHasherDefault, DefaultHasher, Hash, Hasher};
+    use std::sync::RwLock;
+
+    /// `(type of the Beam, hash of the props)`
+    type Key = (TypeId, u64);
+
+    /// props and their HTML, for all props of the same `Key`
+    type Bucket = Vec<(Box<dyn Any + Send + Sync>, Rope)>;
+
+    struct Cache {
+        buckets: HashMap<Key, Bucket, BuildHasherDefault<DefaultHasher>>,
+        len: usize,
+    }
+
+    static CACHE: RwLock<Cache> = RwLock::new(Cache {
+        buckets: HashMap::with_hasher(BuildHasherDefault::new()),
+        len: 0,
+    });
+
+    pub(super) fn key<B: Hash + 'static>(beam: &B) -> Key {
+        let mut hasher = DefaultHasher::new();
+        beam.hash(&mut hasher);
+        (TypeId::of::<B>(), hasher.finish())
+    }
+
+    fn find<'b, B: Eq + 'static>(bucket: &'b Bucket, beam: &B) -> Option<&'b Rope> {
+        bucket
+            .iter()
+            .find(|(props, _)| props.downcast_ref::<B>() == Some(beam))
+            .map(|(_, html)| html)
+    }
+
+    pub(super) fn get<B: Eq + 'static>(key: &Key, beam: &B) -> Option<Rope> {
+        let cache = CACHE.read().unwrap_or_else(|e| e.into_inner());
+        find(cache.buckets.get(key)?, beam).cloned()
+    }
+
+    pub(super) fn insert<B: Eq + Send + Sync + 'static>(key: Key, beam: B, html: Rope) -> Rope {
+        // nonce slots are kept to be filled for each response
+        let html = html.share();
+        let mut cache = CACHE.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(cached) = cache.buckets.get(&key).and_then(|b| find(b, &beam)) {
+            return cached.clone();
+        }
+        if cache.len < super::Memo::CAPACITY {
+            cache.len += 1;
+            let bucket = cache.buckets.entry(key).or_default();
+            bucket.push((Box::new(beam), html.clone()));
+        }
+        html
+    }
+}
+
+impl<B: Beam + Hash + Eq + Clone + Send + Sync + 'static> Beam for Memo<B> {
+    fn render(self) -> UI {
+        #[cfg(not(all(feature = "client", hydrate)))]
+        {
+            let key = cache::key(&self.0);
+            match cache::get(&key, &self.0) {
+                Some(html) => UI(html),
+                None => UI(cache::insert(key, self.0.clone(), self.0.render().0)),
+            }
+        }
+
+        #[cfg(all(feature = "client", hydrate))]
+        {
+            self.0.render()
+        }
+    }
+}
+
+impl<B: Beam + Hash + Eq + Clone + Send + Sync + 'static, const ESCAPE: bool>
+    IntoChildren<Memo<()>, ESCAPE> for Memo<B>
+{
+    #[inline]
+    fn into_children(self) -> UI {
+        Beam::<crate::Server>::render(self)
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "client"))]
+mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_memo() {
+        static RENDERED: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Hash, PartialEq, Eq, Clone)]
+        struct Footer {
+            year: u16,
+        }
+        impl Beam for Footer {
+            fn render(self) -> UI {
+                RENDERED.fetch_add(1, Ordering::Relaxed);
+                UI! { <footer>"(c) "{self.year}" uibeam"</footer> }
+            }
+        }
+
+        for _ in 0..3 {
+            let ui = UI! { <body>{Memo(Footer { year: 2025 })}</body> };
+            assert_eq!(
+                crate::shoot(ui),
+                "<body><footer>(c) 2025 uibeam</footer></body>"
+            );
+        }
+        assert_eq!(RENDERED.load(Ordering::Relaxed), 1);
+
+        assert_eq!(
+            crate::shoot(UI! { {Memo(Footer { year: 2026 })} }),
+            "<footer>(c) 2026 uibeam</footer>"
+        );
+        assert_eq!(RENDERED.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_memo_hash_collision() {
+        /// every value has the same hash
+        #[derive(PartialEq, Eq, Clone)]
+        struct Greeting(&'static str);
+        impl Hash for Greeting {
+            fn hash<H: std::hash::Hasher>(&self, _: &mut H) {}
+        }
+        impl Beam for Greeting {
+            fn render(self) -> UI {
+                UI! { <p>{self.0}</p> }
+            }
+        }
+
+        assert_eq!(
+            crate::shoot(UI! { {Memo(Greeting("hello"))} }),
+            "<p>hello</p>"
+        );
+        assert_eq!(crate::shoot(UI! { {Memo(Greeting("bye"))} }), "<p>bye</p>");
+        assert_eq!(
+            crate::shoot(UI! { {Memo(Greeting("hello"))} }),
+            "<p>hello</p>"
+        );
+    }
+}
@@ -0,0 +1,521 @@
+use std::{borrow::Cow, fmt, sync::Arc};
+
+/// HTML of a server `UI` as a rope: a tree of `&'static str` pieces and
+/// owned segments, assembled into a string just once by `shoot`.
+///
+/// Nested `UI`s are moved into their parent as segments, instead of copying
+/// their bytes into the parent's buffer at every level of the component tree.
+#[derive(Clone, Debug)]
+pub(crate) enum Rope {
+    /// a single piece (const-constructible)
+    Str(Cow<'static, str>),
+    /// a short text without allocation
+    Inline(Inline),
+    /// a text shared with a cache, like `Memo`'s
+    Shared(Arc<str>),
+    /// segments and their total length
+    Segments(Vec<Segment>, usize),
+}
+
+#[derive(Clone, Debug)]
+pub(crate) enum Segment {
+    Str(Cow<'static, str>),
+    Inline(Inline),
+    Rope(Rope),
+    /// slot for ` nonce="{nonce}"` in a start tag written in a template,
+    /// filled only by `shoot_with_nonce`
+    Nonce,
+}
+
+/// a piece of [`Rope`] visited in order
+enum Chunk<'r> {
+    Str(&'r str),
+    Nonce,
+}
+
+impl Rope {
+    pub(crate) const EMPTY: Rope = Rope::Str(Cow::Borrowed(""));
+
+    #[inline]
+    pub(crate) const fn from_static(s: &'static str) -> Self {
+        Rope::Str(Cow::Borrowed(s))
+    }
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Rope::Str(s) => s.len(),
+            Rope::Inline(s) => s.len(),
+            Rope::Shared(s) => s.len(),
+            Rope::Segments(_, len) => *len,
+        }
+    }
+
+    fn try_for_each_chunk<E>(
+        &self,
+        f: &mut impl FnMut(Chunk<'_>) -> Result<(), E>,
+    ) -> Result<(), E> {
+        match self {
+            Rope::Str(s) => f(Chunk::Str(s)),
+            Rope::Inline(s) => f(Chunk::Str(s.as_str())),
+            Rope::Shared(s) => f(Chunk::Str(s)),
+            Rope::Segments(segments, _) => segments.iter().try_for_each(|segment| match segment {
+                Segment::Str(s) => f(Chunk::Str(s)),
+                Segment::Inline(s) => f(Chunk::Str(s.as_str())),
+                Segment::Rope(rope) => rope.try_for_each_chunk(f),
+                Segment::Nonce => f(Chunk::Nonce),
+            }),
+        }
+    }
+
+    /// calls `f` for every string piece in order
+    pub(crate) fn try_for_each<E>(
+        &self,
+        f: &mut impl FnMut(&str) -> Result<(), E>,
+    ) -> Result<(), E> {
+        self.try_for_each_chunk(&mut |chunk| match chunk {
+            Chunk::Str(s) => f(s),
+            Chunk::Nonce => Ok(()),
+        })
+    }
+
+    /// the HTML string, borrowed when this is a single piece
+    pub(crate) fn to_str(&self) -> Cow<'_, str> {
+        match self {
+            Rope::Str(s) => Cow::Borrowed(s),
+            Rope::Inline(s) => Cow::Borrowed(s.as_str()),
+            Rope::Shared(s) => Cow::Borrowed(s),
+            Rope::Segments(..) => Cow::Owned(self.assemble()),
+        }
+    }
+
+    /// the HTML string, with just one allocation for segments
+    pub(crate) fn into_str(self) -> Cow<'static, str> {
+        match self {
+            Rope::Str(s) => s,
+            Rope::Inline(s) => Cow::Owned(s.as_str().to_owned()),
+            Rope::Shared(s) => Cow::Owned(s.as_ref().to_owned()),
+            Rope::Segments(..) => Cow::Owned(self.assemble()),
+        }
+    }
+
+    fn assemble(&self) -> String {
+        let mut html = String::with_capacity(self.len());
+        let _ = self.try_for_each(&mut |s| {
+            html.push_str(s);
+            Ok::<_, ()>(())
+        });
+        html
+    }
+
+    /// the HTML string with `nonce` filled in the nonce slots.
+    ///
+    /// `nonce` must be valid in an attribute value without escaping.
+    #[cfg(feature = "csp")]
+    pub(crate) fn assemble_with_nonce(&self, nonce: &str) -> String {
+        let mut html = String::with_capacity(self.len() + 64);
+        let _ = self.try_for_each_chunk(&mut |chunk| {
+            match chunk {
+                Chunk::Str(s) => html.push_str(s),
+                Chunk::Nonce => {
+                    html.push_str(" nonce=\"");
+                    html.push_str(nonce);
+                    html.push('"');
+                }
+            }
+            Ok::<_, ()>(())
+        });
+        html
+    }
+
+    /// this rope compacted into shared strings between the nonce slots,
+    /// to be cloned cheaply like `Memo`'s cache
+    pub(crate) fn share(&self) -> Rope {
+        let (mut segments, mut buf) = (Vec::new(), String::with_capacity(self.len()));
+        let _ = self.try_for_each_chunk(&mut |chunk| {
+            match chunk {
+                Chunk::Str(s) => buf.push_str(s),
+                Chunk::Nonce => {
+                    segments.push(Segment::Rope(Rope::Shared(std::mem::take(&mut buf).into())));
+                    segments.push(Segment::Nonce);
+                }
+            }
+            Ok::<_, ()>(())
+        });
+        match segments.is_empty() {
+            true => Rope::Shared(buf.into()),
+            false => {
+                segments.push(Segment::Rope(Rope::Shared(buf.into())));
+                Rope::Segments(segments, self.len())
+            }
+        }
+    }
+
+    pub(crate) fn eq_str(&self, other: &str) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        let mut rest = other.as_bytes();
+        self.try_for_each(&mut |s| {
+            let (head, tail) = rest.split_at(s.len());
+            rest = tail;
+            match head == s.as_bytes() {
+                true => Ok(()),
+                false => Err(()),
+            }
+        })
+        .is_ok()
+    }
+}
+
+/// Builds a [`Rope`], copying short pieces into a buffer and
+/// moving long ones or nested ropes as segments.
+///
+/// After a segment is pushed, following pieces are also pushed as segments
+/// (static pieces are borrowed) to avoid allocating another buffer.
+pub(crate) struct Builder {
+    segments: Vec<Segment>,
+    buf: String,
+    len: usize,
+}
+
+impl Builder {
+    /// max length of a piece to be copied into the buffer
+    const INLINE_MAX: usize = 128;
+    /// initial capacity of the segments
+    const SEGMENTS: usize = 8;
+
+    /// `capacity` of the buffer in bytes
+    #[inline]
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            segments: Vec::new(),
+            buf: String::with_capacity(capacity),
+            len: 0,
+        }
+    }
+
+    /// length of `rope` to be copied into the buffer by `push_rope`
+    #[inline]
+    pub(crate) fn inline_len(rope: &Rope) -> usize {
+        match rope {
+            Rope::Str(s) if s.len() <= Self::INLINE_MAX => s.len(),
+            Rope::Inline(s) => s.len(),
+            Rope::Shared(s) if s.len() <= Self::INLINE_MAX => s.len(),
+            _ => 0,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn push_str(&mut self, s: &'static str) {
+        self.push_cow(Cow::Borrowed(s));
+    }
+
+    pub(crate) fn push_cow(&mut self, s: Cow<'static, str>) {
+        if self.segments.is_empty() && s.len() <= Self::INLINE_MAX {
+            self.buf.push_str(&s);
+        } else if !s.is_empty() {
+            self.len += s.len();
+            self.flush();
+            self.segments.push(Segment::Str(s));
+        }
+    }
+
+    pub(crate) fn push_inline(&mut self, s: Inline) {
+        if self.segments.is_empty() {
+            self.buf.push_str(s.as_str());
+        } else if s.len() > 0 {
+            self.len += s.len();
+            self.segments.push(Segment::Inline(s));
+        }
+    }
+
+    pub(crate) fn push_rope(&mut self, rope: Rope) {
+        match rope {
+            Rope::Str(s) => self.push_cow(s),
+            Rope::Inline(s) => self.push_inline(s),
+            Rope::Shared(s) if self.segments.is_empty() && s.len() <= Self::INLINE_MAX => {
+                self.buf.push_str(&s);
+            }
+            Rope::Shared(s) if s.is_empty() => (),
+            rope => {
+                self.len += rope.len();
+                self.flush();
+                self.segments.push(Segment::Rope(rope));
+            }
+        }
+    }
+
+    /// pushes a nonce slot, just before the end of a start tag
+    pub(crate) fn push_nonce(&mut self) {
+        self.flush();
+        self.segments.push(Segment::Nonce);
+    }
+
+    fn flush(&mut self) {
+        if self.segments.is_empty() {
+            // a template seldom has more segments than this
+            self.segments.reserve(Self::SEGMENTS);
+        }
+        if !self.buf.is_empty() {
+            let buf = std::mem::take(&mut self.buf);
+            self.len += buf.len();
+            self.segments.push(Segment::Str(Cow::Owned(buf)));
+        }
+    }
+
+    pub(crate) fn finish(mut self) -> Rope {
+        if self.segments.is_empty() {
+            return Rope::Str(Cow::Owned(self.buf));
+        }
+        self.flush();
+        match self.segments.len() {
+            1 => match self.segments.pop().unwrap() {
+                Segment::Str(s) => Rope::Str(s),
+                Segment::Inline(s) => Rope::Inline(s),
+                Segment::Rope(rope) => rope,
+                nonce => Rope::Segments(vec![nonce], 0),
+            },
+            _ => Rope::Segments(self.segments, self.len),
+        }
+    }
+}
+
+/// A short text stored inline, like a formatted integer
+#[derive(Clone, Copy)]
+pub(crate) struct Inline {
+    len: u8,
+    bytes: [u8; Inline::CAPACITY],
+}
+
+impl Inline {
+    /// enough for any `i64` or `u64`
+    pub(crate) const CAPACITY: usize = 22;
+
+    pub(crate) const EMPTY: Inline = Inline {
+        len: 0,
+        bytes: [0; Inline::CAPACITY],
+    };
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    #[inline]
+    pub(crate) fn as_str(&self) -> &str {
+        // SAFETY: `bytes[..len]` is always written from `&str`s or ASCII digits
+        unsafe { std::str::from_utf8_unchecked(&self.bytes[..self.len as usize]) }
+    }
+
+    /// decimal digits of `int`
+    pub(crate) fn uint(mut int: u64) -> Self {
+        // digits are written from the end, then moved to the start
+        let mut bytes = [0; Inline::CAPACITY];
+        let mut start = Inline::CAPACITY;
+        loop {
+            start -= 1;
+            bytes[start] = b'0' + (int % 10) as u8;
+            int /= 10;
+            if int == 0 {
+                break;
+            }
+        }
+        bytes.copy_within(start.., 0);
+        Inline {
+            len: (Inline::CAPACITY - start) as u8,
+            bytes,
+        }
+    }
+
+    /// decimal digits of `int`, with `-` if negative
+    pub(crate) fn int(int: i64) -> Self {
+        let digits = Inline::uint(int.unsigned_abs());
+        match int < 0 {
+            false => digits,
+            true => {
+                let mut signed = Inline::EMPTY;
+                signed.bytes[0] = b'-';
+                signed.bytes[1..=digits.len()].copy_from_slice(digits.as_str().as_bytes());
+                signed.len = digits.len + 1;
+                signed
+            }
+        }
+    }
+}
+
+impl fmt::Write for Inline {
+    /// fails when `s` doesn't fit in the rest of the capacity
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let len = self.len as usize;
+        let Some(rest) = self.bytes.get_mut(len..len + s.len()) else {
+            return Err(fmt::Error);
+        };
+        rest.copy_from_slice(s.as_bytes());
+        self.len += s.len() as u8;
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Inline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// A text written by `fmt::Write`, stored inline while it's short
+/// and then spilled into a `String`
+pub(crate) enum Text {
+    Inline(Inline),
+    Owned(String),
+}
+
+impl Text {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Text::Inline(Inline::EMPTY)
+    }
+
+    pub(crate) fn into_rope(self) -> Rope {
+        match self {
+            Text::Inline(s) => Rope::Inline(s),
+            Text::Owned(s) => Rope::Str(Cow::Owned(s)),
+        }
+    }
+}
+
+impl fmt::Write for Text {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match self {
+            Text::Inline(inline) => {
+                if inline.write_str(s).is_err() {
+                    let mut owned =
+                        String::with_capacity((inline.len() + s.len()).next_power_of_two());
+                    owned.push_str(inline.as_str());
+                    owned.push_str(s);
+                    *self = Text::Owned(owned);
+                }
+            }
+            Text::Owned(owned) => owned.push_str(s),
+        }
+        Ok(())
+    }
+}
+
+impl PartialEq for Rope {
+    fn eq(&self, other: &Self) -> bool {
+        match other {
+            Rope::Str(s) => self.eq_str(s),
+            Rope::Inline(s) => self.eq_str(s.as_str()),
+            Rope::Shared(s) => self.eq_str(s),
+            Rope::Segments(..) => self.len() == other.len() && self.eq_str(&other.to_str()),
+        }
+    }
+}
+
+impl PartialEq<&str> for Rope {
+    fn eq(&self, other: &&str) -> bool {
+        self.eq_str(other)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rope() {
+        let long = "long ".repeat(Builder::INLINE_MAX);
+        let long: &'static str = Box::leak(long.into_boxed_str());
+
+        let mut child = Builder::with_capacity(0);
+        child.push_str("<b>");
+        child.push_str(long);
+        child.push_cow(Cow::Owned("</b>".into()));
+        let child = child.finish();
+        assert!(matches!(&child, Rope::Segments(segments, _) if segments.len() == 3));
+        assert_eq!(Builder::inline_len(&child), 0);
+
+        let mut rope = Builder::with_capacity(16);
+        rope.push_str("<p");
+        rope.push_cow(Cow::Owned(" hidden".into()));
+        rope.push_str(">");
+        rope.push_rope(Rope::from_static("text"));
+        rope.push_rope(child);
+        rope.push_rope(Rope::EMPTY);
+        rope.push_str("</p>");
+        let rope = rope.finish();
+
+        let expected = format!("<p hidden>text<b>{long}</b></p>");
+        assert_eq!(rope.len(), expected.len());
+        let mut pieces = Vec::new();
+        let _ = rope.try_for_each(&mut |s| {
+            pieces.push(s.to_owned());
+            Ok::<_, ()>(())
+        });
+        assert_eq!(pieces, ["<p hidden>text", "<b>", long, "</b>", "</p>"]);
+
+        assert!(rope.eq_str(&expected));
+        assert!(!rope.eq_str(&expected.replace("text", "test")));
+        assert!(!rope.eq_str(&format!("{expected}!")));
+        assert!(rope == Rope::Str(expected.clone().into()));
+        assert_eq!(rope.clone().to_str(), expected);
+        assert_eq!(rope.into_str(), expected);
+
+        let mut single = Builder::with_capacity(0);
+        single.push_str(long);
+        assert!(matches!(single.finish().into_str(), Cow::Borrowed(s) if s == long));
+    }
+
+    #[test]
+    fn test_nonce() {
+        let mut rope = Builder::with_capacity(0);
+        rope.push_str("<script");
+        rope.push_nonce();
+        rope.push_str(">");
+        rope.push_rope(Rope::from_static("let a = 1;"));
+        rope.push_str("</script>");
+        let rope = rope.finish();
+
+        assert_eq!(rope.to_str(), "<script>let a = 1;</script>");
+        #[cfg(feature = "csp")]
+        assert_eq!(
+            rope.assemble_with_nonce("n"),
+            r#"<script nonce="n">let a = 1;</script>"#
+        );
+
+        let shared = rope.share();
+        assert!(matches!(&shared, Rope::Segments(segments, _) if segments.len() == 3));
+        assert_eq!(shared.len(), rope.len());
+        #[cfg(feature = "csp")]
+        assert_eq!(
+            shared.assemble_with_nonce("n"),
+            r#"<script nonce="n">let a = 1;</script>"#
+        );
+        assert!(matches!(Rope::from_static("<p>").share(), Rope::Shared(_)));
+    }
+
+    #[test]
+    fn test_inline() {
+        for int in [0, 9, 10, -1, -10, 1234567890, i64::MAX, i64::MIN] {
+            assert_eq!(Inline::int(int).as_str(), int.to_string());
+        }
+        assert_eq!(Inline::uint(u64::MAX).as_str(), u64::MAX.to_string());
+
+        let mut rope = Builder::with_capacity(0);
+        rope.push_str("a".repeat(Builder::INLINE_MAX + 1).leak());
+        rope.push_inline(Inline::int(-42));
+        rope.push_rope(Rope::Inline(Inline::EMPTY));
+        let rope = rope.finish();
+        assert!(matches!(&rope, Rope::Segments(segments, _) if segments.len() == 2));
+        assert!(rope.to_str().ends_with("a-42"));
+
+        use std::fmt::Write;
+        let mut text = Text::new();
+        text.write_str(&"a".repeat(Inline::CAPACITY)).unwrap();
+        assert!(matches!(text, Text::Inline(_)));
+        text.write_str("b").unwrap();
+        assert!(matches!(&text, Text::Owned(s) if s.len() == Inline::CAPACITY + 1));
+    }
+}
@@ -0,0 +1,190 @@
+//! Trusted values for attributes with security-sensitive contexts.
+//!
+//! In `UI!`, an interpolated attribute value is sanitized for the context
+//! of the attribute (see [`uibeam_html::AttributeContext`]):
+//!
+//! | context | attributes | untrusted value |
+//! |---------|------------|-----------------|
+//! | URL     | `href`, `src`, `action`, `formaction`, `data`, `srcset`, ... | dangerous schemes like `javascript:` are neutralized into [`uibeam_html::INVALID_URL`] |
+//! | CSS     | `style` | dangerous declarations like `expression(...)` are replaced with [`uibeam_html::INVALID_CSS`] |
+//! | HTML    | `srcdoc` | escaped as a text in the embedded document |
+//! | JS      | `onclick` and any other `on*` | rejected at compile time (only event handlers of `#[client]` Beams are allowed) |
+//!
+//! The types here are the explicit escape hatches: each is rendered as it is
+//! (of course, HTML-escaped as an attribute value) in its own context.
+//! In any other context, it is treated just as an untrusted text.
+//!
+//! Literal attribute values like `href="javascript:void(0)"` are written
+//! by the author, so they are not checked.
+//!
+//! ```compile_fail
+//! use uibeam::UI;
+//!
+//! let handler = "alert(1)";
+//! let _ = UI! { <button onclick={handler}>"click"</button> };
+//! ```
+//!
+//! ```compile_fail
+//! use uibeam::UI;
+//!
+//! let handler = "alert(1)";
+//! let _ = UI! { <script onafterscriptexecute={handler}></script> };
+//! ```
+
+use crate::AttributeValue;
+use std::borrow::Cow;
+use uibeam_html::AttributeContext;
+
+/// # `SafeUrl` - URL trusted in URL attributes
+///
+/// ```
+/// use uibeam::{UI, SafeUrl};
+///
+/// let user_input = "javascript:alert(1)";
+/// assert_eq!(
+///     UI! { <a href={user_input}>"link"</a> },
+///     r#"<a href="about:invalid#uibeam">link</a>"#
+/// );
+///
+/// let app_link = SafeUrl::trusted("myapp://settings");
+/// assert_eq!(
+///     UI! { <a href={app_link}>"settings"</a> },
+///     r#"<a href="myapp://settings">settings</a>"#
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SafeUrl(Cow<'static, str>);
+
+impl SafeUrl {
+    /// Trusts `url` as it is, **without any checks**.
+    ///
+    /// Make sure `url` doesn't come from any untrusted input.
+    pub fn trusted(url: impl Into<Cow<'static, str>>) -> Self {
+        Self(url.into())
+    }
+
+    /// `Some` if `url` is relative or its scheme is one of
+    /// [`uibeam_html::SAFE_URL_SCHEMES`], `None` otherwise.
+    pub fn new(url: impl Into<Cow<'static, str>>) -> Option<Self> {
+        let url = url.into();
+        (uibeam_html::sanitize_url(&url) == url).then_some(Self(url))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// # `SafeCss` - CSS declarations trusted in `style` attribute
+///
+/// ```
+/// use uibeam::{UI, SafeCss};
+///
+/// let user_input = "background: url(javascript:alert(1))";
+/// assert_eq!(
+///     UI! { <div style={user_input}></div> },
+///     r#"<div style="/*invalid#uibeam*/"></div>"#
+/// );
+///
+/// let theme = SafeCss::trusted("background: url(data:image/png;base64,iVBORw0KG...)");
+/// assert_eq!(
+///     UI! { <div style={theme}></div> },
+///     r#"<div style="background: url(data:image/png;base64,iVBORw0KG...)"></div>"#
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SafeCss(Cow<'static, str>);
+
+impl SafeCss {
+    /// Trusts `css` as it is, **without any checks**.
+    ///
+    /// Make sure `css` doesn't come from any untrusted input.
+    pub fn trusted(css: impl Into<Cow<'static, str>>) -> Self {
+        Self(css.into())
+    }
+
+    /// `Some` if `css` passes [`uibeam_html::sanitize_css`], `None` otherwise.
+    pub fn new(css: impl Into<Cow<'static, str>>) -> Option<Self> {
+        let css = css.into();
+        (uibeam_html::sanitize_css(&css) == css).then_some(Self(css))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// # `SafeHtml` - HTML trusted in `srcdoc` attribute
+///
+/// `UI` can be converted into `SafeHtml` on the server, because `UI`
+/// is already safe HTML.
+///
+/// ```
+/// use uibeam::{UI, SafeHtml};
+///
+/// let user_input = "<script>alert(1)</script>";
+/// assert_eq!(
+///     UI! { <iframe srcdoc={user_input}></iframe> },
+///     r#"<iframe srcdoc="&amp;lt;script&amp;gt;alert(1)&amp;lt;/script&amp;gt;"></iframe>"#
+/// );
+///
+/// let preview = SafeHtml::from(UI! { <p>{user_input}</p> });
+/// assert_eq!(
+///     UI! { <iframe srcdoc={preview}></iframe> },
+///     r#"<iframe srcdoc="&lt;p&gt;&amp;lt;script&amp;gt;alert(1)&amp;lt;/script&amp;gt;&lt;/p&gt;"></iframe>"#
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SafeHtml(Cow<'static, str>);
+
+impl SafeHtml {
+    /// Trusts `html` as it is, **without any checks**.
+    ///
+    /// Make sure `html` doesn't come from any untrusted input.
+    pub fn trusted(html: impl Into<Cow<'static, str>>) -> Self {
+        Self(html.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(not(all(feature = "client", hydrate)))]
+impl From<crate::UI> for SafeHtml {
+    fn from(ui: crate::UI) -> Self {
+        Self(ui.0.into_str())
+    }
+}
+
+const _: () = {
+    impl From<SafeUrl> for AttributeValue {
+        #[inline(always)]
+        fn from(url: SafeUrl) -> Self {
+            AttributeValue::Trusted(AttributeContext::Url, url.0)
+        }
+    }
+
+    impl From<SafeCss> for AttributeValue {
+        #[inline(always)]
+        fn from(css: SafeCss) -> Self {
+            AttributeValue::Trusted(AttributeContext::Css, css.0)
+        }
+    }
+
+    impl From<SafeHtml> for AttributeValue {
+        #[inline(always)]
+        fn from(html: SafeHtml) -> Self {
+            AttributeValue::Trusted(AttributeContext::Html, html.0)
+        }
+    }
+};
+
+/// whether a value trusted in `context` is also trusted in the `target` context
+pub(crate) fn is_trusted_in(context: AttributeContext, target: AttributeContext) -> bool {
+    match (context, target) {
+        (AttributeContext::Url, AttributeContext::UrlList) => true,
+        (AttributeContext::Js, _) | (_, AttributeContext::Js) => false,
+        (context, target) => context == target,
+    }
+}
@@ -0,0 +1,156 @@
+use std::borrow::Cow;
+
+/// # `SizeHint` - estimated length of rendered HTML
+///
+/// The estimated length in bytes of the HTML rendered from a value,
+/// used to reserve buffers before writing into them.
+///
+/// `UI!` reserves the total length of its static pieces, computed at
+/// compile time, plus the `size_hint` of every interpolated value, so
+/// a template is rendered with just one allocation in the common case.
+///
+/// Implement this for your own types, e.g. a `Beam` rendering roughly
+/// fixed size of HTML, to reserve the right capacity for them in
+/// `UI::from_iter` or in your own buffers:
+///
+/// ```
+/// use uibeam::{Beam, SizeHint, UI};
+///
+/// struct Badge {
+///     count: u32,
+/// }
+/// impl Beam for Badge {
+///     fn render(self) -> UI {
+///         UI! { <span class="badge">{self.count}</span> }
+///     }
+/// }
+/// impl SizeHint for Badge {
+///     fn size_hint(&self) -> usize {
+///         r#"<span class="badge"></span>"#.len() + self.count.size_hint()
+///     }
+/// }
+///
+/// let badge = Badge { count: 42 };
+/// assert_eq!(badge.size_hint(), uibeam::shoot(UI! { <Badge count={42} /> }).len());
+/// ```
+pub trait SizeHint {
+    fn size_hint(&self) -> usize;
+}
+
+#[cfg(not(all(feature = "client", hydrate)))]
+impl SizeHint for crate::UI {
+    /// exact length of the HTML
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl SizeHint for str {
+    /// length before escaping
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
+}
+impl SizeHint for String {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
+}
+impl SizeHint for Cow<'_, str> {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
+}
+impl SizeHint for char {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.len_utf8()
+    }
+}
+impl SizeHint for bool {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        match self {
+            true => "true".len(),
+            false => "false".len(),
+        }
+    }
+}
+
+macro_rules! integers {
+    ($($int:ty),*) => {$(
+        impl SizeHint for $int {
+            /// exact number of the decimal digits, including the sign
+            #[inline]
+            fn size_hint(&self) -> usize {
+                let int = *self as i128;
+                let digits = int.unsigned_abs().checked_ilog10().map_or(1, |log| log as usize + 1);
+                (int < 0) as usize + digits
+            }
+        }
+    )*};
+}
+integers!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl<T: SizeHint + ?Sized> SizeHint for &T {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        (**self).size_hint()
+    }
+}
+impl<T: SizeHint + ?Sized> SizeHint for Box<T> {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        (**self).size_hint()
+    }
+}
+impl<T: SizeHint> SizeHint for Option<T> {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.as_ref().map_or(0, T::size_hint)
+    }
+}
+impl<T: SizeHint> SizeHint for [T] {
+    fn size_hint(&self) -> usize {
+        self.iter().map(T::size_hint).sum()
+    }
+}
+impl<T: SizeHint> SizeHint for Vec<T> {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.as_slice().size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_integer_size_hint() {
+        for int in [
+            0,
+            7,
+            9,
+            10,
+            99,
+            100,
+            12345,
+            -1,
+            -9,
+            -10,
+            -12345,
+            i64::MAX,
+            i64::MIN,
+        ] {
+            assert_eq!(int.size_hint(), int.to_string().len(), "{int}");
+        }
+        assert_eq!(u64::MAX.size_hint(), u64::MAX.to_string().len());
+        assert_eq!(i8::MIN.size_hint(), 4);
+        assert_eq!(0_u8.size_hint(), 1);
+    }
+}
@@ -0,0 +1,707 @@
+//! # `testing` - query helpers for rendered HTML
+//!
+//! [`Document`] parses the output of [`shoot`](crate::shoot) into a lightweight DOM,
+//! so that tests can assert on its structure instead of exact strings:
+//!
+//! ```
+//! use uibeam::{UI, Beam};
+//! use uibeam::testing::Document;
+//!
+//! struct Form {
+//!     name: &'static str,
+//! }
+//! impl Beam for Form {
+//!     fn render(self) -> UI {
+//!         UI! {
+//!             <form class="profile" action="/profile">
+//!                 <input type="text" name="name" value={self.name} />
+//!                 <button type="submit" class="btn primary">"Save"</button>
+//!             </form>
+//!         }
+//!     }
+//! }
+//!
+//! let document = Document::render(UI! { <Form name="uibeam" /> });
+//!
+//! let button = document.find("form.profile > button.primary").unwrap();
+//! assert_eq!(button.text(), "Save");
+//!
+//! let input = document.find("input[name=name]").unwrap();
+//! assert_eq!(input.attribute("value"), Some("uibeam"));
+//! ```
+//!
+//! Supported selectors are:
+//!
+//! - type `div`, universal `*`, class `.name`, id `#name`
+//! - attribute `[name]`, `[name=value]`, `[name~=value]`, `[name|=value]`,
+//!   `[name^=value]`, `[name$=value]`, `[name*=value]`
+//! - combinators: descendant (` `), child (`>`), next-sibling (`+`)
+//!   and subsequent-sibling (`~`)
+//! - selector list (`,`)
+//!
+//! Since `Document` reads the server-side rendering, islands
+//! (`#[client(island)]` Beams) are queried as their initial HTML,
+//! together with the `data-uibeam-*` attributes for hydration.
+//!
+//! For comparing the whole output, see [`assert_snapshot!`].
+
+mod snapshot;
+
+#[doc(inline)]
+pub use crate::__assert_snapshot as assert_snapshot;
+#[doc(hidden)]
+pub use snapshot::{__assert_snapshot, SnapshotLocation};
+
+use crate::UI;
+use uibeam_html::{Token, is_raw_text_element, is_void_element, tokenize};
+
+/// A parsed HTML document or fragment.
+#[derive(Clone)]
+pub struct Document {
+    nodes: Vec<Node>,
+}
+
+#[derive(Clone)]
+struct Node {
+    parent: Option<usize>,
+    children: Vec<usize>,
+    kind: NodeKind,
+}
+
+#[derive(Clone)]
+enum NodeKind {
+    Root,
+    Element {
+        /// lowercased
+        name: String,
+        /// (lowercased name, unescaped value)
+        attributes: Vec<(String, String)>,
+    },
+    /// unescaped
+    Text(String),
+    Comment,
+}
+
+const ROOT: usize = 0;
+
+impl Document {
+    /// Parses HTML, typically the output of [`shoot`](crate::shoot).
+    pub fn parse(html: &str) -> Self {
+        let mut nodes = vec![Node {
+            parent: None,
+            children: Vec::new(),
+            kind: NodeKind::Root,
+        }];
+        let mut current = ROOT;
+        let mut in_raw_text = false;
+
+        fn push(nodes: &mut Vec<Node>, parent: usize, kind: NodeKind) -> usize {
+            let id = nodes.len();
+            nodes.push(Node {
+                parent: Some(parent),
+                children: Vec::new(),
+                kind,
+            });
+            nodes[parent].children.push(id);
+            id
+        }
+
+        for token in tokenize(html) {
+            match token {
+                Token::Doctype(_) => (),
+                Token::Comment(_) => {
+                    push(&mut nodes, current, NodeKind::Comment);
+                }
+                Token::Text(text) => {
+                    let text = if in_raw_text {
+                        text.to_string()
+                    } else {
+                        uibeam_html::unescape(text).into_owned()
+                    };
+                    push(&mut nodes, current, NodeKind::Text(text));
+                }
+                Token::StartTag(tag) => {
+                    let element = push(
+                        &mut nodes,
+                        current,
+                        NodeKind::Element {
+                            name: tag.name().to_ascii_lowercase(),
+                            attributes: tag
+                                .attributes()
+                                .map(|(name, value)| {
+                                    (
+                                        name.to_ascii_lowercase(),
+                                        value
+                                            .map(|v| {
+                                                uibeam_html::unescape_attribute(v).into_owned()
+                                            })
+                                            .unwrap_or_default(),
+                                    )
+                                })
+                                .collect(),
+                        },
+                    );
+                    if !(tag.is_self_closing() || is_void_element(tag.name())) {
+                        current = element;
+                        // `<title>` and `<textarea>` are escapable
+                        in_raw_text = is_raw_text_element(tag.name())
+                            && !matches!(&*tag.name().to_ascii_lowercase(), "title" | "textarea");
+                    }
+                }
+                Token::EndTag(tag) => {
+                    in_raw_text = false;
+                    // close up to the matching element, ignoring an unmatched end tag
+                    let mut open = current;
+                    while open != ROOT {
+                        if matches!(&nodes[open].kind, NodeKind::Element { name, .. } if name.eq_ignore_ascii_case(tag.name()))
+                        {
+                            current = nodes[open].parent.unwrap();
+                            break;
+                        }
+                        open = nodes[open].parent.unwrap();
+                    }
+                }
+            }
+        }
+
+        Self { nodes }
+    }
+
+    /// Renders `ui` by [`shoot`](crate::shoot) and parses the output.
+    pub fn render(ui: UI) -> Self {
+        Self::parse(&crate::shoot(ui))
+    }
+
+    /// All elements matching `selector`, in document order.
+    ///
+    /// ## Panics
+    ///
+    /// When `selector` is invalid or not supported.
+    pub fn select(&self, selector: &str) -> Vec<Element<'_>> {
+        self.root().select(selector)
+    }
+
+    /// The first element matching `selector`.
+    ///
+    /// ## Panics
+    ///
+    /// When `selector` is invalid or not supported.
+    pub fn find(&self, selector: &str) -> Option<Element<'_>> {
+        self.root().find(selector)
+    }
+
+    /// Concatenated text content of the whole document.
+    pub fn text(&self) -> String {
+        self.root().text()
+    }
+
+    fn root(&self) -> Element<'_> {
+        Element {
+            document: self,
+            id: ROOT,
+        }
+    }
+}
+
+impl From<UI> for Document {
+    fn from(ui: UI) -> Self {
+        Self::render(ui)
+    }
+}
+
+/// An element in a [`Document`].
+#[derive(Clone, Copy)]
+pub struct Element<'d> {
+    document: &'d Document,
+    id: usize,
+}
+
+impl<'d> Element<'d> {
+    /// Tag name in lowercase.
+    pub fn name(&self) -> &'d str {
+        match &self.node().kind {
+            NodeKind::Element { name, .. } => name,
+            _ => "",
+        }
+    }
+
+    /// Unescaped value of the attribute, or `Some("")` for a boolean attribute
+    /// like `checked`.
+    pub fn attribute(&self, name: &str) -> Option<&'d str> {
+        self.attributes()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+
+    pub fn has_attribute(&self, name: &str) -> bool {
+        self.attribute(name).is_some()
+    }
+
+    /// `(name, value)` pairs of the attributes in source order.
+    pub fn attributes(&self) -> impl Iterator<Item = (&'d str, &'d str)> + 'd {
+        let attributes = match &self.document.nodes[self.id].kind {
+            NodeKind::Element { attributes, .. } => &attributes[..],
+            _ => &[],
+        };
+        attributes.iter().map(|(n, v)| (&**n, &**v))
+    }
+
+    /// Classes in the `class` attribute.
+    pub fn classes(&self) -> impl Iterator<Item = &'d str> + 'd {
+        self.attribute("class")
+            .unwrap_or_default()
+            .split_ascii_whitespace()
+    }
+
+    pub fn has_class(&self, class: &str) -> bool {
+        self.classes().any(|c| c == class)
+    }
+
+    /// Concatenated, unescaped text content of all the descendants.
+    pub fn text(&self) -> String {
+        fn collect(document: &Document, id: usize, buf: &mut String) {
+            for &child in &document.nodes[id].children {
+                match &document.nodes[child].kind {
+                    NodeKind::Text(text) => buf.push_str(text),
+                    NodeKind::Element { .. } => collect(document, child, buf),
+                    _ => (),
+                }
+            }
+        }
+        let mut buf = String::new();
+        collect(self.document, self.id, &mut buf);
+        buf
+    }
+
+    /// Child elements.
+    pub fn children(&self) -> impl Iterator<Item = Element<'d>> + 'd {
+        let document = self.document;
+        document.nodes[self.id]
+            .children
+            .iter()
+            .filter(|&&id| matches!(document.nodes[id].kind, NodeKind::Element { .. }))
+            .map(move |&id| Element { document, id })
+    }
+
+    /// Parent element, `None` for a top-level element.
+    pub fn parent(&self) -> Option<Element<'d>> {
+        self.node()
+            .parent
+            .filter(|&id| id != ROOT)
+            .map(|id| Element {
+                document: self.document,
+                id,
+            })
+    }
+
+    /// All descendant elements matching `selector`, in document order.
+    ///
+    /// ## Panics
+    ///
+    /// When `selector` is invalid or not supported.
+    pub fn select(&self, selector: &str) -> Vec<Element<'d>> {
+        let selectors = parse_selectors(selector);
+        let mut found = Vec::new();
+        self.for_each_descendant(&mut |element| {
+            if selectors.iter().any(|s| element.matches_complex(s)) {
+                found.push(element);
+            }
+        });
+        found
+    }
+
+    /// The first descendant element matching `selector`.
+    ///
+    /// ## Panics
+    ///
+    /// When `selector` is invalid or not supported.
+    pub fn find(&self, selector: &str) -> Option<Element<'d>> {
+        self.select(selector).into_iter().next()
+    }
+
+    /// Whether this element matches `selector`.
+    ///
+    /// ## Panics
+    ///
+    /// When `selector` is invalid or not supported.
+    pub fn matches(&self, selector: &str) -> bool {
+        parse_selectors(selector)
+            .iter()
+            .any(|s| self.matches_complex(s))
+    }
+
+    fn node(&self) -> &'d Node {
+        &self.document.nodes[self.id]
+    }
+
+    fn for_each_descendant(&self, f: &mut impl FnMut(Element<'d>)) {
+        for child in self.children() {
+            f(child);
+            child.for_each_descendant(f);
+        }
+    }
+
+    fn previous_siblings(&self) -> impl Iterator<Item = Element<'d>> + 'd {
+        let (document, id) = (self.document, self.id);
+        let siblings = match self.node().parent {
+            Some(parent) => &document.nodes[parent].children[..],
+            None => &[],
+        };
+        let position = siblings.iter().position(|&s| s == id).unwrap_or(0);
+        siblings[..position]
+            .iter()
+            .rev()
+            .filter(|&&id| matches!(document.nodes[id].kind, NodeKind::Element { .. }))
+            .map(move |&id| Element { document, id })
+    }
+
+    fn matches_complex(&self, parts: &[Part]) -> bool {
+        let Some((last, rest)) = parts.split_last() else {
+            return false;
+        };
+        if !self.matches_compound(&last.compound) {
+            return false;
+        }
+        if rest.is_empty() {
+            return true;
+        }
+        match last.combinator {
+            Combinator::Descendant => {
+                let mut ancestor = self.parent();
+                while let Some(a) = ancestor {
+                    if a.matches_complex(rest) {
+                        return true;
+                    }
+                    ancestor = a.parent();
+                }
+                false
+            }
+            Combinator::Child => self.parent().is_some_and(|p| p.matches_complex(rest)),
+            Combinator::NextSibling => self
+                .previous_siblings()
+                .next()
+                .is_some_and(|s| s.matches_complex(rest)),
+            Combinator::SubsequentSibling => {
+                self.previous_siblings().any(|s| s.matches_complex(rest))
+            }
+        }
+    }
+
+    fn matches_compound(&self, compound: &Compound) -> bool {
+        compound
+            .name
+            .as_ref()
+            .is_none_or(|name| name.eq_ignore_ascii_case(self.name()))
+            && compound
+                .ids
+                .iter()
+                .all(|id| self.attribute("id") == Some(id))
+            && compound.classes.iter().all(|class| self.has_class(class))
+            && compound.attributes.iter().all(|a| {
+                let Some(value) = self.attribute(&a.name) else {
+                    return false;
+                };
+                let Some((operator, expected)) = &a.condition else {
+                    return true;
+                };
+                match *operator {
+                    "=" => value == expected,
+                    "~=" => value.split_ascii_whitespace().any(|v| v == expected),
+                    "|=" => value == expected || value.starts_with(&format!("{expected}-")),
+                    "^=" => !expected.is_empty() && value.starts_with(&**expected),
+                    "$=" => !expected.is_empty() && value.ends_with(&**expected),
+                    "*=" => !expected.is_empty() && value.contains(&**expected),
+                    _ => unreachable!(),
+                }
+            })
+    }
+}
+
+impl std::fmt::Debug for Element<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{}", self.name())?;
+        for (name, value) in self.attributes() {
+            write!(f, " {name}={value:?}")?;
+        }
+        f.write_str(">")
+    }
+}
+
+impl std::fmt::Debug for Document {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.root().children()).finish()
+    }
+}
+
+/* selectors */
+
+/// compound selectors from left to right
+type Complex = Vec<Part>;
+
+struct Part {
+    /// combinator between the previous compound and this
+    combinator: Combinator,
+    compound: Compound,
+}
+
+enum Combinator {
+    Descendant,
+    Child,
+    NextSibling,
+    SubsequentSibling,
+}
+
+#[derive(Default)]
+struct Compound {
+    name: Option<String>,
+    ids: Vec<String>,
+    classes: Vec<String>,
+    attributes: Vec<AttributeSelector>,
+}
+
+struct AttributeSelector {
+    name: String,
+    condition: Option<(&'static str, String)>,
+}
+
+fn parse_selectors(selector: &str) -> Vec<Complex> {
+    let invalid = |reason: &str| -> ! { panic!("invalid selector `{selector}`: {reason}") };
+
+    let mut chars = selector.chars().peekable();
+    let mut selectors = vec![Complex::new()];
+    let mut combinator = None;
+
+    let ident = |chars: &mut std::iter::Peekable<std::str::Chars<'_>>| {
+        let mut ident = String::new();
+        while let Some(&c) = chars.peek() {
+            if !(c.is_alphanumeric() || matches!(c, '-' | '_') || !c.is_ascii()) {
+                break;
+            }
+            ident.push(c);
+            chars.next();
+        }
+        ident
+    };
+
+    loop {
+        let mut has_space = false;
+        while chars.next_if(|c| c.is_ascii_whitespace()).is_some() {
+            has_space = true;
+        }
+        let Some(&c) = chars.peek() else { break };
+
+        let complex = selectors.last_mut().unwrap();
+        match c {
+            ',' => {
+                if complex.is_empty() || combinator.is_some() {
+                    invalid("empty selector in the list");
+                }
+                chars.next();
+                selectors.push(Complex::new());
+                continue;
+            }
+            '>' | '+' | '~' => {
+                if complex.is_empty() || combinator.is_some() {
+                    invalid("unexpected combinator");
+                }
+                chars.next();
+                combinator = Some(match c {
+                    '>' => Combinator::Child,
+                    '+' => Combinator::NextSibling,
+                    _ => Combinator::SubsequentSibling,
+                });
+                continue;
+            }
+            _ => (),
+        }
+
+        let combinator = match combinator.take() {
+            Some(combinator) => combinator,
+            None if complex.is_empty() => Combinator::Descendant,
+            None if has_space => Combinator::Descendant,
+            None => invalid("unexpected character"),
+        };
+
+        let mut compound = Compound::default();
+        if chars.next_if_eq(&'*').is_none() {
+            let name = ident(&mut chars);
+            if !name.is_empty() {
+                compound.name = Some(name);
+            }
+        }
+        loop {
+            match chars.peek() {
+                Some('.') => {
+                    chars.next();
+                    match ident(&mut chars) {
+                        class if !class.is_empty() => compound.classes.push(class),
+                        _ => invalid("missing class name"),
+                    }
+                }
+                Some('#') => {
+                    chars.next();
+                    match ident(&mut chars) {
+                        id if !id.is_empty() => compound.ids.push(id),
+                        _ => invalid("missing id"),
+                    }
+                }
+                Some('[') => {
+                    chars.next();
+                    while chars.next_if(|c| c.is_ascii_whitespace()).is_some() {}
+                    let name = ident(&mut chars).to_ascii_lowercase();
+                    if name.is_empty() {
+                        invalid("missing attribute name");
+                    }
+                    while chars.next_if(|c| c.is_ascii_whitespace()).is_some() {}
+                    let operator = match chars.next() {
+                        Some(']') => {
+                            compound.attributes.push(AttributeSelector {
+                                name,
+                                condition: None,
+                            });
+                            continue;
+                        }
+                        Some('=') => "=",
+                        Some(c) if chars.next_if_eq(&'=').is_some() => match c {
+                            '~' => "~=",
+                            '|' => "|=",
+                            '^' => "^=",
+                            '$' => "$=",
+                            '*' => "*=",
+                            _ => invalid("unknown attribute operator"),
+                        },
+                        _ => invalid("unknown attribute operator"),
+                    };
+                    while chars.next_if(|c| c.is_ascii_whitespace()).is_some() {}
+                    let value = match chars.next_if(|c| matches!(c, '"' | '\'')) {
+                        Some(quote) => chars.by_ref().take_while(|c| *c != quote).collect(),
+                        None => ident(&mut chars),
+                    };
+                    while chars.next_if(|c| c.is_ascii_whitespace()).is_some() {}
+                    if chars.next() != Some(']') {
+                        invalid("unclosed attribute selector");
+                    }
+                    compound.attributes.push(AttributeSelector {
+                        name,
+                        condition: Some((operator, value)),
+                    });
+                }
+                Some(':') => invalid("pseudo-classes are not supported"),
+                _ => break,
+            }
+        }
+
+        complex.push(Part {
+            combinator,
+            compound,
+        });
+        if chars
+            .peek()
+            .is_some_and(|c| !(c.is_ascii_whitespace() || matches!(c, ',' | '>' | '+' | '~')))
+        {
+            invalid("unexpected character");
+        }
+    }
+
+    if selectors.iter().any(Vec::is_empty) || combinator.is_some() {
+        invalid("incomplete selector");
+    }
+    selectors
+}
+
+#[cfg(not(feature = "client"))]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const HTML: &str = r##"<!DOCTYPE html><html><head><title>a &amp; b</title><script>if (a < b) {}</script></head><body><main id="main"><ul class="items compact"><li data-id="1">one</li><li data-id="2" class="selected">two &lt;2&gt;</li><li data-id="3">three</li></ul><p>before<!-- comment --><a href="/next?a=1&amp;b=2">next</a></p><input type="checkbox" checked/></main></body></html>"##;
+
+    fn names(elements: Vec<Element<'_>>) -> Vec<String> {
+        elements
+            .iter()
+            .map(|e| match e.attribute("data-id") {
+                Some(id) => format!("{}#{id}", e.name()),
+                None => e.name().to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_select() {
+        let document = Document::parse(HTML);
+
+        for (selector, expected) in [
+            ("li", &["li#1", "li#2", "li#3"][..]),
+            ("ul.items > li.selected", &["li#2"]),
+            ("#main li", &["li#1", "li#2", "li#3"]),
+            ("body > li", &[]),
+            ("li + li", &["li#2", "li#3"]),
+            ("li.selected ~ li", &["li#3"]),
+            ("[data-id='3'], a, title", &["title", "li#3", "a"]),
+            ("li[data-id=\"2\"]", &["li#2"]),
+            ("[class~=compact]", &["ul"]),
+            ("a[href^=\"/next\"][href$=\"b=2\"]", &["a"]),
+            ("input[checked]", &["input"]),
+            ("*[type*=check]", &["input"]),
+        ] {
+            assert_eq!(names(document.select(selector)), expected, "{selector}");
+        }
+    }
+
+    #[test]
+    fn test_text_and_attributes() {
+        let document = Document::parse(HTML);
+
+        assert_eq!(document.find("title").unwrap().text(), "a & b");
+        assert_eq!(document.find("script").unwrap().text(), "if (a < b) {}");
+        assert_eq!(document.find("ul").unwrap().text(), "onetwo <2>three");
+        assert_eq!(document.find("p").unwrap().text(), "beforenext");
+
+        let a = document.find("p a").unwrap();
+        assert_eq!(a.attribute("HREF"), Some("/next?a=1&b=2"));
+        assert_eq!(a.parent().unwrap().name(), "p");
+
+        let input = document.find("input").unwrap();
+        assert_eq!(input.attribute("checked"), Some(""));
+        assert!(!input.has_attribute("value"));
+        assert!(input.matches("main > [type=checkbox]"));
+
+        let ul = document.find("ul").unwrap();
+        assert_eq!(ul.classes().collect::<Vec<_>>(), ["items", "compact"]);
+        assert_eq!(ul.children().count(), 3);
+        assert_eq!(ul.select("li").len(), 3);
+    }
+
+    #[test]
+    fn test_render() {
+        struct Item {
+            label: &'static str,
+        }
+        impl crate::Beam for Item {
+            fn render(self) -> UI {
+                UI! { <li class="item">{self.label}</li> }
+            }
+        }
+
+        let document = Document::render(UI! {
+            <ul>
+                <Item label="<one>" />
+                <Item label="two" />
+            </ul>
+        });
+        assert_eq!(
+            document
+                .select("ul > li.item")
+                .iter()
+                .map(Element::text)
+                .collect::<Vec<_>>(),
+            ["<one>", "two"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "pseudo-classes are not supported")]
+    fn test_unsupported_selector() {
+        Document::parse(HTML).select("li:first-child");
+    }
+}
@@ -0,0 +1,308 @@
+use crate::UI;
+use std::path::{Path, PathBuf};
+
+/// environment variable to update snapshots instead of asserting
+const UPDATE_ENV: &str = "UIBEAM_UPDATE_SNAPSHOTS";
+
+/// replacement of masked attribute values
+const MASKED: &str = "[masked]";
+
+/// # `assert_snapshot!` - snapshot testing for rendered `UI`
+///
+/// Renders `UI` by [`shoot`](crate::shoot), normalizes it into
+/// pretty-printed HTML, and compares with the snapshot file at
+/// `snapshots/{source file stem}__{name}.html` next to the test source file.
+/// `name` defaults to the name of the test function.
+///
+/// A missing or mismatched snapshot fails the assertion; run tests with
+/// `UIBEAM_UPDATE_SNAPSHOTS=1` to create or update the snapshots.
+///
+/// ```no_run
+/// use uibeam::UI;
+/// use uibeam::testing::assert_snapshot;
+///
+/// #[test]
+/// fn test_greeting() {
+///     // compared with `snapshots/{file}__test_greeting.html`
+///     assert_snapshot!(UI! { <p>"Hello!"</p> });
+///
+///     // compared with `snapshots/{file}__farewell.html`
+///     assert_snapshot!(UI! { <p>"Bye!"</p> }, "farewell");
+///
+///     // values of the specified attributes are replaced with `[masked]`
+///     assert_snapshot!(
+///         UI! { <p data-rendered-at={now()}>"Hello!"</p> },
+///         "greeting_with_timestamp",
+///         mask = ["data-rendered-at"],
+///     );
+/// }
+/// # fn now() -> String { String::new() }
+/// ```
+///
+/// When the snapshot differs, it panics with a line diff.
+///
+/// Each `assert_snapshot!` call in a test function needs its own `name`
+/// except for one of them.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_snapshot {
+    ($ui:expr $(, mask = [$($mask:expr),* $(,)?])? $(,)?) => {
+        $crate::testing::__assert_snapshot(
+            $ui,
+            $crate::__snapshot_location!(),
+            None,
+            &[$($($mask),*)?],
+        )
+    };
+    ($ui:expr, $name:expr $(, mask = [$($mask:expr),* $(,)?])? $(,)?) => {
+        $crate::testing::__assert_snapshot(
+            $ui,
+            $crate::__snapshot_location!(),
+            Some($name),
+            &[$($($mask),*)?],
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __snapshot_location {
+    () => {
+        $crate::testing::SnapshotLocation {
+            manifest_dir: env!("CARGO_MANIFEST_DIR"),
+            file: file!(),
+            function: {
+                fn f() {}
+                fn type_name_of<T>(_: T) -> &'static str {
+                    ::std::any::type_name::<T>()
+                }
+                type_name_of(f)
+            },
+        }
+    };
+}
+
+#[doc(hidden)]
+pub struct SnapshotLocation {
+    pub manifest_dir: &'static str,
+    pub file: &'static str,
+    /// `type_name` of `f` defined in the test function
+    pub function: &'static str,
+}
+
+impl SnapshotLocation {
+    fn path(&self, name: Option<&str>) -> PathBuf {
+        // `file!()` is relative to the workspace root, not to the package
+        let file = Path::new(self.file);
+        let source = match file.is_absolute() {
+            true => file.to_path_buf(),
+            false => Path::new(self.manifest_dir)
+                .ancestors()
+                .map(|dir| dir.join(file))
+                .find(|path| path.exists())
+                .unwrap_or_else(|| Path::new(self.manifest_dir).join(file)),
+        };
+
+        let name = name.unwrap_or_else(|| {
+            self.function
+                .rsplit("::")
+                .find(|segment| !matches!(*segment, "f" | "{{closure}}"))
+                .unwrap_or("snapshot")
+        });
+        let stem = source
+            .file_stem()
+            .map(|s| s.to_string_lossy())
+            .unwrap_or_default();
+
+        source
+            .parent()
+            .unwrap_or(Path::new("."))
+            .join("snapshots")
+            .join(format!("{stem}__{name}.html"))
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn __assert_snapshot(ui: UI, location: SnapshotLocation, name: Option<&str>, mask: &[&str]) {
+    let path = location.path(name);
+    let actual = normalize(&crate::shoot(ui), mask);
+
+    let update = std::env::var_os(UPDATE_ENV).is_some_and(|v| !v.is_empty() && v != "0");
+    let expected = std::fs::read_to_string(&path).ok();
+
+    match expected {
+        Some(expected) if expected.trim_end() == actual => (),
+        Some(_) | None if update => {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)
+                    .unwrap_or_else(|e| panic!("failed to create `{}`: {e}", dir.display()));
+            }
+            std::fs::write(&path, actual + "\n")
+                .unwrap_or_else(|e| panic!("failed to write `{}`: {e}", path.display()));
+            eprintln!("[uibeam] snapshot `{}` is written", path.display());
+        }
+        None => panic!(
+            "snapshot `{}` is not found\n\
+            (run with `{UPDATE_ENV}=1` to create it)",
+            path.display()
+        ),
+        Some(expected) => panic!(
+            "snapshot `{}` does not match:\n\
+            {}\n\
+            (run with `{UPDATE_ENV}=1` to update it)",
+            path.display(),
+            diff(expected.trim_end(), &actual)
+        ),
+    }
+}
+
+/// pretty-prints `html` with values of the `mask` attributes replaced
+fn normalize(html: &str, mask: &[&str]) -> String {
+    use uibeam_html::{Token, tokenize};
+
+    if mask.is_empty() {
+        return uibeam_html::pretty(html);
+    }
+
+    let mut masked = String::with_capacity(html.len());
+    for token in tokenize(html) {
+        match token {
+            Token::StartTag(tag)
+                if tag
+                    .attributes()
+                    .any(|(name, _)| mask.iter().any(|m| m.eq_ignore_ascii_case(name))) =>
+            {
+                masked.push('<');
+                masked.push_str(tag.name());
+                for (name, value) in tag.attributes() {
+                    masked.push(' ');
+                    masked.push_str(name);
+                    if let Some(value) = value {
+                        masked.push_str("=\"");
+                        masked.push_str(match mask.iter().any(|m| m.eq_ignore_ascii_case(name)) {
+                            true => MASKED,
+                            false => value,
+                        });
+                        masked.push('"');
+                    }
+                }
+                masked.push_str(if tag.is_self_closing() { "/>" } else { ">" });
+            }
+            _ => masked.push_str(token.as_str()),
+        }
+    }
+    uibeam_html::pretty(&masked)
+}
+
+/// line diff by LCS, with `-` for `expected` and `+` for `actual`
+fn diff(expected: &str, actual: &str) -> String {
+    let (expected, actual) = (
+        expected.lines().collect::<Vec<_>>(),
+        actual.lines().collect::<Vec<_>>(),
+    );
+    let (n, m) = (expected.len(), actual.len());
+
+    // lcs[i][j] = LCS length of expected[i..] and actual[j..]
+    let mut lcs = vec![vec![0_usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = match expected[i] == actual[j] {
+                true => lcs[i + 1][j + 1] + 1,
+                false => lcs[i + 1][j].max(lcs[i][j + 1]),
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        let line = if i < n && j < m && expected[i] == actual[j] {
+            i += 1;
+            j += 1;
+            format!("  {}", actual[j - 1])
+        } else if i < n && (j == m || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            i += 1;
+            format!("- {}", expected[i - 1])
+        } else {
+            j += 1;
+            format!("+ {}", actual[j - 1])
+        };
+        diff.push_str(&line);
+        diff.push('\n');
+    }
+    diff.pop();
+    diff
+}
+
+#[cfg(not(feature = "client"))]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            normalize(
+                r##"<div data-uibeam-props="{&#34;a&#34;:1}" id="x"><input DATA-UIBEAM-PROPS='2' checked/></div>"##,
+                &["data-uibeam-props"],
+            ),
+            "<div data-uibeam-props=\"[masked]\" id=\"x\"><input DATA-UIBEAM-PROPS=\"[masked]\" checked/></div>"
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(
+            diff(
+                "<ul>\n  <li>a</li>\n  <li>b</li>\n</ul>",
+                "<ul>\n  <li>a</li>\n  <li>c</li>\n</ul>"
+            ),
+            "  <ul>\n    <li>a</li>\n-   <li>b</li>\n+   <li>c</li>\n  </ul>"
+        );
+    }
+
+    #[test]
+    fn test_snapshot_path() {
+        let location = crate::__snapshot_location!();
+        assert_eq!(
+            location.path(None),
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("src/testing/snapshots/snapshot__test_snapshot_path.html")
+        );
+        assert_eq!(
+            location.path(Some("named")).file_name().unwrap(),
+            "snapshot__named.html"
+        );
+    }
+
+    #[test]
+    fn test_missing_snapshot() {
+        if std::env::var_os(UPDATE_ENV).is_some() {
+            // the snapshot would be created
+            return;
+        }
+        let panic = std::panic::catch_unwind(|| {
+            crate::testing::assert_snapshot!(UI! { <p>"missing"</p> }, "missing");
+        })
+        .unwrap_err();
+        assert!(
+            panic
+                .downcast_ref::<String>()
+                .is_some_and(|message| message.contains("is not found"))
+        );
+    }
+
+    #[test]
+    fn test_assert_snapshot() {
+        crate::testing::assert_snapshot!(
+            UI! {
+                <main>
+                    <h1 class="title">"Snapshot"</h1>
+                    <p data-rendered-at={std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_nanos().to_string()}>"rendered"</p>
+                </main>
+            },
+            mask = ["data-rendered-at"],
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! Compares `uibeam_html::escape` with the previous byte-by-byte implementation.
+//!
+//! ```sh
+//! cargo bench -p uibeam_html --bench escape
+//! ```
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use std::borrow::Cow;
+use std::hint::black_box;
+
+/// the previous implementation, scanning and pushing byte by byte
+fn escape_bytewise(s: &str) -> Cow<'_, str> {
+    let mut first_special = None;
+    for i in 0..s.len() {
+        match &s.as_bytes()[i] {
+            b'&' | b'<' | b'>' | b'"' | b'\'' => {
+                first_special = Some(i);
+                break;
+            }
+            _ => (),
+        }
+    }
+
+    match first_special {
+        None => Cow::Borrowed(s),
+        Some(f) => {
+            let mut escaped = Vec::with_capacity(s.len() + 10);
+            escaped.extend_from_slice(&s.as_bytes()[..f]);
+            for b in &s.as_bytes()[f..] {
+                match b {
+                    b'&' => escaped.extend_from_slice(b"&amp;"),
+                    b'<' => escaped.extend_from_slice(b"&lt;"),
+                    b'>' => escaped.extend_from_slice(b"&gt;"),
+                    b'"' => escaped.extend_from_slice(b"&#34;"),
+                    b'\'' => escaped.extend_from_slice(b"&#39;"),
+                    _ => escaped.push(*b),
+                }
+            }
+            Cow::Owned(String::from_utf8(escaped).unwrap())
+        }
+    }
+}
+
+fn escape(c: &mut Criterion) {
+    let inputs = [
+        (
+            "ASCII, no special characters",
+            "The quick brown fox jumps over the lazy dog. ".repeat(100),
+        ),
+        (
+            "ASCII, sparse special characters",
+            "Tom & Jerry say \"hello\" to the lazy dog. ".repeat(100),
+        ),
+        (
+            "CJK, no special characters",
+            "素早い茶色の狐がのろまな犬を飛び越える。".repeat(100),
+        ),
+        (
+            "CJK, sparse special characters",
+            "「素早い茶色の狐」が<のろまな犬>を飛び越える。".repeat(100),
+        ),
+        ("heavily escaped", "<a href=\"x\">'&'</a>".repeat(100)),
+        ("short", "Hello, world!".to_string()),
+    ];
+
+    let mut group = c.benchmark_group("escape");
+    for (name, input) in &inputs {
+        assert_eq!(escape_bytewise(input), uibeam_html::escape(input));
+
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::new("bytewise", name), input, |b, input| {
+            b.iter(|| escape_bytewise(black_box(input)).len())
+        });
+        group.bench_with_input(BenchmarkId::new("escape", name), input, |b, input| {
+            b.iter(|| uibeam_html::escape(black_box(input)).len())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, escape);
+criterion_main!(benches);
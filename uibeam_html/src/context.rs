@@ -0,0 +1,245 @@
+use crate::{escape, sanitize_srcset, sanitize_url};
+use std::borrow::Cow;
+
+/// The value that a dangerous `style` attribute value is replaced with.
+pub const INVALID_CSS: &str = "/*invalid#uibeam*/";
+
+/// Context of an attribute value, deciding how an untrusted value
+/// is sanitized by [`sanitize_attribute`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttributeContext {
+    /// plain text, like `class` or `title`: only HTML-escaped
+    Text,
+    /// a URL, like `href` or `src`: checked by [`sanitize_url`]
+    Url,
+    /// a list of URLs for `srcset`: checked by [`sanitize_srcset`]
+    UrlList,
+    /// CSS declarations for `style`: checked by [`sanitize_css`]
+    Css,
+    /// an HTML document for `srcdoc`: escaped as a text
+    Html,
+    /// JavaScript for event handlers like `onclick`: never accepted
+    Js,
+}
+
+impl AttributeContext {
+    /// Classifies the attribute by its name (ASCII case-insensitive).
+    ///
+    /// Every `on*` attribute is taken as an event handler, including
+    /// ones unknown to uibeam or introduced by browsers in the future.
+    ///
+    /// ```
+    /// use uibeam_html::AttributeContext;
+    ///
+    /// assert_eq!(AttributeContext::of("class"), AttributeContext::Text);
+    /// assert_eq!(AttributeContext::of("HREF"), AttributeContext::Url);
+    /// assert_eq!(AttributeContext::of("style"), AttributeContext::Css);
+    /// assert_eq!(AttributeContext::of("onclick"), AttributeContext::Js);
+    /// assert_eq!(AttributeContext::of("onbeforescriptexecute"), AttributeContext::Js);
+    /// ```
+    pub fn of(name: &str) -> Self {
+        const URL: &[&str] = &[
+            "href",
+            "src",
+            "action",
+            "formaction",
+            "poster",
+            "cite",
+            "data",
+            "xlink:href",
+            // legacy
+            "background",
+            "ping",
+        ];
+
+        let is = |n: &str| name.eq_ignore_ascii_case(n);
+        if URL.iter().any(|n| is(n)) {
+            Self::Url
+        } else if is("srcset") {
+            Self::UrlList
+        } else if is("style") {
+            Self::Css
+        } else if is("srcdoc") {
+            Self::Html
+        } else if name.len() > 2 && name.as_bytes()[..2].eq_ignore_ascii_case(b"on") {
+            Self::Js
+        } else {
+            Self::Text
+        }
+    }
+
+    /// Sanitizes an untrusted `value` for this context,
+    /// in the same way as [`sanitize_attribute`].
+    pub fn sanitize(self, value: &str) -> Cow<'_, str> {
+        match self {
+            Self::Text => Cow::Borrowed(value),
+            Self::Url => sanitize_url(value),
+            Self::UrlList => sanitize_srcset(value),
+            Self::Css => sanitize_css(value),
+            Self::Html => escape(value),
+            Self::Js => Cow::Borrowed(""),
+        }
+    }
+}
+
+/// Sanitizes an untrusted `value` for the context of the attribute `name`
+/// (see [`AttributeContext`]).
+///
+/// Note that the result is not HTML-escaped yet, except for
+/// [`AttributeContext::Html`] where the value is escaped as a text
+/// in the embedded document.
+///
+/// ```
+/// use uibeam_html::sanitize_attribute;
+///
+/// assert_eq!(sanitize_attribute("title", "javascript:alert(1)"), "javascript:alert(1)");
+/// assert_eq!(sanitize_attribute("href", "javascript:alert(1)"), "about:invalid#uibeam");
+/// assert_eq!(sanitize_attribute("style", "color: red"), "color: red");
+/// assert_eq!(sanitize_attribute("style", "background: url(javascript:alert(1))"), "/*invalid#uibeam*/");
+/// assert_eq!(sanitize_attribute("srcdoc", "<script>alert(1)</script>"), "&lt;script&gt;alert(1)&lt;/script&gt;");
+/// assert_eq!(sanitize_attribute("onclick", "alert(1)"), "");
+/// ```
+pub fn sanitize_attribute<'v>(name: &str, value: &'v str) -> Cow<'v, str> {
+    AttributeContext::of(name).sanitize(value)
+}
+
+/// Replaces CSS declarations with [`INVALID_CSS`] when they may execute
+/// scripts or load unsafe resources: `expression(...)`, `-moz-binding`,
+/// `behavior`, `@import`, or `url(...)` / `image-set(...)` with a URL
+/// rejected by [`sanitize_url`].
+///
+/// CSS comments and escapes are resolved before the check, so
+/// obfuscations like `ex/**/pression(` or `\75 rl(` are detected.
+///
+/// ```
+/// use uibeam_html::{sanitize_css, INVALID_CSS};
+///
+/// assert_eq!(sanitize_css("color: red; background: url(/bg.png)"), "color: red; background: url(/bg.png)");
+/// assert_eq!(sanitize_css("width: expression(alert(1))"), INVALID_CSS);
+/// assert_eq!(sanitize_css("background: \\75 rl('java\\73 cript:alert(1)')"), INVALID_CSS);
+/// ```
+pub fn sanitize_css(css: &str) -> Cow<'_, str> {
+    let normalized = normalize_css(css);
+
+    const DANGEROUS: &[&str] = &["expression(", "-moz-binding", "behavior:", "@import"];
+    if DANGEROUS.iter().any(|d| normalized.contains(d))
+        || urls_in_css(&normalized).any(|url| sanitize_url(url) != url)
+    {
+        return Cow::Borrowed(INVALID_CSS);
+    }
+    Cow::Borrowed(css)
+}
+
+/// lowercased `css` with comments, escapes and whitespaces resolved
+fn normalize_css(css: &str) -> String {
+    let mut normalized = String::with_capacity(css.len());
+    let mut chars = css.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = '\0';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            '\\' => {
+                let mut hex = String::new();
+                while hex.len() < 6 && chars.peek().is_some_and(char::is_ascii_hexdigit) {
+                    hex.push(chars.next().unwrap());
+                }
+                if hex.is_empty() {
+                    // `\` + any character means the character itself
+                    normalized.extend(chars.next().map(|c| c.to_ascii_lowercase()));
+                } else {
+                    // a whitespace after a hex escape is a part of the escape
+                    chars.next_if(char::is_ascii_whitespace);
+                    normalized.extend(
+                        u32::from_str_radix(&hex, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .map(|c| c.to_ascii_lowercase()),
+                    );
+                }
+            }
+            c if c.is_ascii_whitespace() => (),
+            c => normalized.push(c.to_ascii_lowercase()),
+        }
+    }
+    normalized
+}
+
+/// URLs in `url(...)` or `image-set(...)` of normalized CSS
+fn urls_in_css(normalized: &str) -> impl Iterator<Item = &str> {
+    ["url(", "image-set(", "image("]
+        .into_iter()
+        .flat_map(move |function| {
+            normalized.match_indices(function).flat_map(move |(i, _)| {
+                let args = &normalized[i + function.len()..];
+                let args = &args[..args.find(')').unwrap_or(args.len())];
+                args.split(',').map(|arg| {
+                    arg.trim_matches(|c: char| matches!(c, '"' | '\''))
+                        .split(['"', '\''])
+                        .next()
+                        .unwrap_or_default()
+                })
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attribute_context_of_event_handlers() {
+        for name in [
+            "onclick",
+            "OnClick",
+            "onafterscriptexecute",
+            "onbeforescriptexecute",
+            "onmozfullscreenchange",
+            "onboarding",
+            "on-anything",
+        ] {
+            assert_eq!(AttributeContext::of(name), AttributeContext::Js, "{name}");
+        }
+        for name in ["on", "o", "", "class", "data-on", "\u{3042}on"] {
+            assert_eq!(AttributeContext::of(name), AttributeContext::Text, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_sanitize_css() {
+        for (css, is_safe) in [
+            ("", true),
+            ("color: red; font-size: 1.2rem", true),
+            ("background: url(/bg.png) no-repeat", true),
+            ("background-image: url('https://example.com/a.png')", true),
+            ("background: image-set('a.png' 1x, \"b.png\" 2x)", true),
+            ("content: '\\201C'", true),
+            ("width: expression(alert(1))", false),
+            ("width: EXPRESSION (alert(1))", false),
+            ("width: ex/**/pression(alert(1))", false),
+            ("width: \\65 xpression(alert(1))", false),
+            ("background: url(javascript:alert(1))", false),
+            ("background: url( \"JavaScript:alert(1)\" )", false),
+            (
+                "background: image-set('a.png' 1x, 'javascript:x' 2x)",
+                false,
+            ),
+            ("-moz-binding: url(x.xml#xss)", false),
+            ("behavior: url(x.htc)", false),
+            ("@import 'x.css'", false),
+        ] {
+            assert_eq!(
+                sanitize_css(css),
+                if is_safe { css } else { INVALID_CSS },
+                "{css:?}"
+            );
+        }
+    }
+}
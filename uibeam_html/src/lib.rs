@@ -1,3 +1,19 @@
+mod context;
+mod pretty;
+mod sanitize;
+mod tokenize;
+mod unescape;
+mod url;
+
+pub use context::{AttributeContext, INVALID_CSS, sanitize_attribute, sanitize_css};
+pub use pretty::pretty;
+pub use sanitize::{Policy, sanitize};
+pub use tokenize::{
+    Attributes, Tag, Token, Tokens, is_raw_text_element, is_void_element, tokenize,
+};
+pub use unescape::{unescape, unescape_attribute};
+pub use url::{INVALID_URL, SAFE_URL_SCHEMES, is_url_attribute, sanitize_srcset, sanitize_url};
+
 use std::borrow::Cow;
 
 /// Escapes HTML special characters in a string.
@@ -15,47 +31,145 @@ use std::borrow::Cow;
 /// it just returns a borrowed reference to the original string.
 #[inline]
 pub fn escape(s: &str) -> Cow<'_, str> {
-    let mut first_special = None;
-    for i in 0..s.len() {
-        match &s.as_bytes()[i] {
-            b'&' | b'<' | b'>' | b'"' | b'\'' => {
-                first_special = Some(i);
-                break;
+    let bytes = s.as_bytes();
+    let Some(mut i) = find_special_chunk(bytes, 0) else {
+        return Cow::Borrowed(s);
+    };
+
+    let mut escaped = Vec::with_capacity(s.len() + 10);
+    escaped.extend_from_slice(&bytes[..i]);
+    while i < bytes.len() {
+        // from the chunk containing special characters, byte by byte
+        // without checking chunks, as special characters tend to be dense
+        let end = (i + DENSE_LEN).min(bytes.len());
+        escaped.reserve((end - i) * 5);
+        // SAFETY:
+        //
+        // - `escaped` has capacity for 5 bytes per input byte, so writing
+        //   5 bytes for each one doesn't overflow (only the first `len` bytes
+        //   of them are kept, and the rest are overwritten by the next one)
+        // - `set_len` is called with the length of the written bytes
+        unsafe {
+            let mut out = escaped.as_mut_ptr().add(escaped.len());
+            for b in &bytes[i..end] {
+                let (entity, len) = &ESCAPED[*b as usize];
+                std::ptr::copy_nonoverlapping(entity.as_ptr(), out, 5);
+                out = out.add(*len as usize);
             }
-            _ => (),
+            escaped.set_len(out.offset_from(escaped.as_ptr()) as usize);
+        }
+        i = end;
+
+        // the following clean chunks, in bulk
+        let next = find_special_chunk(bytes, i).unwrap_or(bytes.len());
+        if next > i {
+            escaped.extend_from_slice(&bytes[i..next]);
+            i = next;
         }
     }
+    // SAFETY: `escaped` is a valid UTF-8 bytes because:
+    //
+    // - original `s` is valid UTF-8
+    // - we just replaced some ASCII bytes with valid UTF-8 bytes
+    // - the rest of `escaped` is unchanged, directly copied from `s`
+    Cow::Owned(unsafe { String::from_utf8_unchecked(escaped) })
+}
 
-    match first_special {
-        None => Cow::Borrowed(s),
-        Some(f) => {
-            let mut escaped = Vec::with_capacity(s.len() + 10);
-            escaped.extend_from_slice(&s.as_bytes()[..f]);
-            for b in &s.as_bytes()[f..] {
-                match b {
-                    b'&' => escaped.extend_from_slice(b"&amp;"),
-                    b'<' => escaped.extend_from_slice(b"&lt;"),
-                    b'>' => escaped.extend_from_slice(b"&gt;"),
-                    b'"' => escaped.extend_from_slice(b"&#34;"), // "&#34;" is shorter than "&quot;".
-                    b'\'' => escaped.extend_from_slice(b"&#39;"), // "&#39;" is shorter than "&apos;" and apos was not in HTML until HTML5.
-                    _ => escaped.push(*b),                        // no need to escape.
-                                                                   // this may make `escaped` invalid UTF-8 **temporarily**, but finally it **builds** a valid UTF-8 bytes.
-                }
-            }
-            // SAFETY: `escaped` is a valid UTF-8 bytes because:
-            //
-            // - original `s` is valid UTF-8
-            // - we just replaced some ASCII bytes with valid UTF-8 bytes
-            // - the rest of `escaped` is unchanged, directly copied from `s`
-            Cow::Owned(unsafe { String::from_utf8_unchecked(escaped) })
+/// [`escape`] writing into `w` directly, without allocating a `String`
+/// for the escaped text.
+///
+/// ```
+/// use uibeam_html::escape_into;
+///
+/// let mut html = String::from("<p>");
+/// escape_into(&mut html, "Tom & Jerry").unwrap();
+/// assert_eq!(html, "<p>Tom &amp; Jerry");
+/// ```
+pub fn escape_into(w: &mut (impl std::fmt::Write + ?Sized), s: &str) -> std::fmt::Result {
+    let bytes = s.as_bytes();
+    let mut clean_start = 0;
+    let mut i = find_special_chunk(bytes, 0).unwrap_or(bytes.len());
+    while i < bytes.len() {
+        let (entity, len) = &ESCAPED[bytes[i] as usize];
+        if *len > 1 {
+            w.write_str(&s[clean_start..i])?;
+            // SAFETY: entities are ASCII
+            w.write_str(unsafe { std::str::from_utf8_unchecked(&entity[..*len as usize]) })?;
+            clean_start = i + 1;
+        }
+        i += 1;
+        if i.is_multiple_of(8) {
+            i = find_special_chunk(bytes, i).unwrap_or(bytes.len());
         }
     }
+    w.write_str(&s[clean_start..])
+}
+
+/// escaped bytes of each byte, padded to 5 bytes, and its length
+const ESCAPED: [([u8; 5], u8); 256] = {
+    let mut table = [([0; 5], 1); 256];
+    let mut b = 0;
+    while b < 256 {
+        table[b] = match b as u8 {
+            b'&' => (*b"&amp;", 5),
+            b'<' => (*b"&lt;\0", 4),
+            b'>' => (*b"&gt;\0", 4),
+            b'"' => (*b"&#34;", 5),  // "&#34;" is shorter than "&quot;".
+            b'\'' => (*b"&#39;", 5), // "&#39;" is shorter than "&apos;" and apos was not in HTML until HTML5.
+            other => ([other, 0, 0, 0, 0], 1), // no need to escape.
+        };
+        b += 1;
+    }
+    table
+};
+
+/// length of a run escaped byte by byte after finding a special character
+const DENSE_LEN: usize = 16;
+
+/// start of the first 8-byte chunk from `bytes[from..]` that contains
+/// any special character of [`escape`]
+///
+/// This checks 8 bytes at once by SWAR (SIMD within a register).
+#[inline(always)]
+fn find_special_chunk(bytes: &[u8], from: usize) -> Option<usize> {
+    const LO7: u64 = u64::from_ne_bytes([0x7f; 8]);
+
+    /// the highest bit of each byte is set if and only if the byte is zero,
+    /// without carries across bytes
+    #[inline(always)]
+    fn zeros(x: u64) -> u64 {
+        !(((x & LO7) + LO7) | x | LO7)
+    }
+
+    let mut i = from;
+    while let Some(chunk) = bytes.get(i..i + 8) {
+        let word = u64::from_ne_bytes(chunk.try_into().unwrap());
+        let specials = zeros(word ^ u64::from_ne_bytes([b'&'; 8]))
+            | zeros(word ^ u64::from_ne_bytes([b'<'; 8]))
+            | zeros(word ^ u64::from_ne_bytes([b'>'; 8]))
+            | zeros(word ^ u64::from_ne_bytes([b'"'; 8]))
+            | zeros(word ^ u64::from_ne_bytes([b'\''; 8]));
+        if specials != 0 {
+            return Some(i);
+        }
+        i += 8;
+    }
+    bytes[i..]
+        .iter()
+        .any(|b| matches!(b, b'&' | b'<' | b'>' | b'"' | b'\''))
+        .then_some(i)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn escape_to_string(s: &str) -> String {
+        let mut escaped = String::new();
+        escape_into(&mut escaped, s).unwrap();
+        escaped
+    }
+
     #[test]
     fn test_escape() {
         let test_cases = [
@@ -84,6 +198,48 @@ mod tests {
 
         for (input, expected) in test_cases {
             assert_eq!(escape(input), expected);
+            assert_eq!(escape_to_string(input), expected);
+        }
+    }
+
+    #[test]
+    fn test_escape_at_every_position() {
+        fn escape_bytewise(s: &str) -> String {
+            s.chars()
+                .map(|c| match c {
+                    '&' => "&amp;".to_string(),
+                    '<' => "&lt;".to_string(),
+                    '>' => "&gt;".to_string(),
+                    '"' => "&#34;".to_string(),
+                    '\'' => "&#39;".to_string(),
+                    c => c.to_string(),
+                })
+                .collect()
+        }
+
+        for special in ['&', '<', '>', '"', '\''] {
+            for len in 0..20 {
+                for at in 0..len {
+                    let input = (0..len)
+                        .map(|i| if i == at { special } else { 'a' })
+                        .collect::<String>();
+                    assert_eq!(escape(&input), escape_bytewise(&input), "{input:?}");
+                    assert_eq!(
+                        escape_to_string(&input),
+                        escape_bytewise(&input),
+                        "{input:?}"
+                    );
+
+                    // a special character after a multi-byte one
+                    let input = format!("あ{input}\u{80}");
+                    assert_eq!(escape(&input), escape_bytewise(&input), "{input:?}");
+                    assert_eq!(
+                        escape_to_string(&input),
+                        escape_bytewise(&input),
+                        "{input:?}"
+                    );
+                }
+            }
         }
     }
 }
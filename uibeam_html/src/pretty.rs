@@ -0,0 +1,370 @@
+use crate::{Token, is_void_element, tokenize};
+
+/// Indents HTML by 2 spaces per nesting level of block elements,
+/// for humans to read, e.g. in debugging, testing or emails.
+///
+/// - Each block element like `<div>` or `<p>`, doctype or comment
+///   is put on its own line.
+/// - Inline content (texts and inline elements like `<a>` or `<b>`)
+///   is kept on one line, with its whitespaces collapsed into a space
+///   and trimmed at the ends.
+/// - Contents of `<pre>`, `<textarea>`, `<script>` and `<style>`
+///   are kept as they are.
+///
+/// This only changes insignificant whitespaces, so browsers render
+/// the output the same as the input in most cases.
+///
+/// ```
+/// assert_eq!(
+///     uibeam_html::pretty("<div><p>Hello, <b>world</b>!</p><br/>bye</div>"),
+///     "<div>\n  <p>Hello, <b>world</b>!</p>\n  <br/>bye\n</div>"
+/// );
+/// ```
+pub fn pretty(html: &str) -> String {
+    let mut out = String::with_capacity(html.len() * 2);
+    write_blocks(&parse(html), 0, &mut out);
+    out
+}
+
+enum Node<'h> {
+    Text(&'h str),
+    /// doctype, comment, void element or self-closing tag,
+    /// with the tag name (empty for doctype or comment)
+    Leaf(&'h str, &'h str),
+    Element {
+        name: &'h str,
+        start: &'h str,
+        children: Vec<Node<'h>>,
+        end: Option<&'h str>,
+    },
+}
+
+fn parse(html: &str) -> Vec<Node<'_>> {
+    // stack of (tag name, start tag, children)
+    let mut stack: Vec<(&str, &str, Vec<Node<'_>>)> = vec![("", "", Vec::new())];
+
+    for token in tokenize(html) {
+        match token {
+            Token::Text(text) => stack.last_mut().unwrap().2.push(Node::Text(text)),
+            Token::Doctype(raw) | Token::Comment(raw) => {
+                stack.last_mut().unwrap().2.push(Node::Leaf(raw, ""))
+            }
+            Token::StartTag(tag) if tag.is_self_closing() || is_void_element(tag.name()) => stack
+                .last_mut()
+                .unwrap()
+                .2
+                .push(Node::Leaf(tag.as_str(), tag.name())),
+            Token::StartTag(tag) => stack.push((tag.name(), tag.as_str(), Vec::new())),
+            Token::EndTag(tag) => {
+                let Some(open) = stack
+                    .iter()
+                    .skip(1)
+                    .rposition(|(name, _, _)| name.eq_ignore_ascii_case(tag.name()))
+                    .map(|i| i + 1)
+                else {
+                    // unmatched end tag
+                    stack
+                        .last_mut()
+                        .unwrap()
+                        .2
+                        .push(Node::Leaf(tag.as_str(), ""));
+                    continue;
+                };
+                while stack.len() > open + 1 {
+                    close(&mut stack, None);
+                }
+                close(&mut stack, Some(tag.as_str()));
+            }
+        }
+    }
+    while stack.len() > 1 {
+        close(&mut stack, None);
+    }
+
+    stack.pop().unwrap().2
+}
+
+fn close<'h>(stack: &mut Vec<(&'h str, &'h str, Vec<Node<'h>>)>, end: Option<&'h str>) {
+    let (name, start, children) = stack.pop().unwrap();
+    stack.last_mut().unwrap().2.push(Node::Element {
+        name,
+        start,
+        children,
+        end,
+    });
+}
+
+impl Node<'_> {
+    fn is_block(&self) -> bool {
+        match self {
+            Node::Text(_) => false,
+            Node::Leaf(_, name) => name.is_empty() || is_block_element(name),
+            Node::Element { name, children, .. } => {
+                is_block_element(name) || children.iter().any(Node::is_block)
+            }
+        }
+    }
+
+    /// the original source of this node
+    fn write_source(&self, out: &mut String) {
+        match self {
+            Node::Text(raw) | Node::Leaf(raw, _) => out.push_str(raw),
+            Node::Element {
+                start,
+                children,
+                end,
+                ..
+            } => {
+                out.push_str(start);
+                for child in children {
+                    child.write_source(out);
+                }
+                out.push_str(end.unwrap_or_default());
+            }
+        }
+    }
+
+    /// writes this inline node into the current line
+    fn write_inline(&self, line: &mut String) {
+        match self {
+            Node::Text(text) => {
+                for c in text.chars() {
+                    match is_whitespace(c) {
+                        true if line.ends_with(' ') => (),
+                        true => line.push(' '),
+                        false => line.push(c),
+                    }
+                }
+            }
+            Node::Element { name, .. } if is_whitespace_preserving(name) => self.write_source(line),
+            Node::Leaf(raw, _) => line.push_str(raw),
+            Node::Element {
+                start,
+                children,
+                end,
+                ..
+            } => {
+                line.push_str(start);
+                for child in children {
+                    child.write_inline(line);
+                }
+                line.push_str(end.unwrap_or_default());
+            }
+        }
+    }
+}
+
+fn write_blocks(nodes: &[Node<'_>], depth: usize, out: &mut String) {
+    let mut line = String::new();
+    for node in nodes {
+        if !node.is_block() {
+            node.write_inline(&mut line);
+            continue;
+        }
+
+        write_trimmed_line(depth, &line, out);
+        line.clear();
+
+        match node {
+            Node::Element {
+                name,
+                start,
+                children,
+                end,
+            } if !is_whitespace_preserving(name) && children.iter().any(Node::is_block) => {
+                write_line(depth, start, out);
+                write_blocks(children, depth + 1, out);
+                if let Some(end) = end {
+                    write_line(depth, end, out);
+                }
+            }
+            Node::Element {
+                name,
+                start,
+                children,
+                end,
+            } if !is_whitespace_preserving(name) => {
+                let mut content = String::new();
+                for child in children {
+                    child.write_inline(&mut content);
+                }
+                let content = content.trim_matches(' ');
+                write_line(
+                    depth,
+                    &format!("{start}{content}{}", end.unwrap_or_default()),
+                    out,
+                );
+            }
+            _ => {
+                let mut source = String::new();
+                node.write_source(&mut source);
+                write_line(depth, &source, out);
+            }
+        }
+    }
+    write_trimmed_line(depth, &line, out);
+}
+
+fn is_block_element(name: &str) -> bool {
+    const BLOCK_ELEMENTS: &[&str] = &[
+        "address",
+        "article",
+        "aside",
+        "base",
+        "blockquote",
+        "body",
+        "caption",
+        "col",
+        "colgroup",
+        "dd",
+        "details",
+        "dialog",
+        "div",
+        "dl",
+        "dt",
+        "fieldset",
+        "figcaption",
+        "figure",
+        "footer",
+        "form",
+        "h1",
+        "h2",
+        "h3",
+        "h4",
+        "h5",
+        "h6",
+        "head",
+        "header",
+        "hgroup",
+        "hr",
+        "html",
+        "li",
+        "link",
+        "main",
+        "menu",
+        "meta",
+        "nav",
+        "noscript",
+        "ol",
+        "optgroup",
+        "option",
+        "p",
+        "pre",
+        "script",
+        "search",
+        "section",
+        "style",
+        "summary",
+        "table",
+        "tbody",
+        "td",
+        "template",
+        "tfoot",
+        "th",
+        "thead",
+        "title",
+        "tr",
+        "ul",
+    ];
+    BLOCK_ELEMENTS.iter().any(|b| b.eq_ignore_ascii_case(name))
+}
+
+fn is_whitespace_preserving(name: &str) -> bool {
+    ["pre", "textarea", "script", "style"]
+        .iter()
+        .any(|p| p.eq_ignore_ascii_case(name))
+}
+
+/// ASCII whitespace of HTML
+fn is_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\x0C' | '\r')
+}
+
+fn write_trimmed_line(depth: usize, line: &str, out: &mut String) {
+    let line = line.trim_matches(' ');
+    if !line.is_empty() {
+        write_line(depth, line, out);
+    }
+}
+
+fn write_line(depth: usize, line: &str, out: &mut String) {
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    for _ in 0..depth {
+        out.push_str("  ");
+    }
+    out.push_str(line);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pretty() {
+        assert_eq!(
+            pretty(
+                r#"<!DOCTYPE html><html><head><title>a < b</title><meta charset="UTF-8"/></head><body>  <div class="x"><p>Count: 5</p><!-- c --><button></button>text<br></div></body></html>"#
+            ),
+            r#"<!DOCTYPE html>
+<html>
+  <head>
+    <title>a < b</title>
+    <meta charset="UTF-8"/>
+  </head>
+  <body>
+    <div class="x">
+      <p>Count: 5</p>
+      <!-- c -->
+      <button></button>text<br>
+    </div>
+  </body>
+</html>"#
+        );
+    }
+
+    #[test]
+    fn test_pretty_inline() {
+        assert_eq!(
+            pretty(
+                "<!DOCTYPE html><html><head><title>a &lt; b</title><meta charset=\"UTF-8\"/><style>p {\n  color: red;\n}</style></head>\
+                <body>\n  <div class=\"x\"><p>\n    Count:\n    <b>5</b>\n  </p><!-- c --><span><a href=\"/\">link</a>\ttext</span><br>\
+                <textarea> a\n  b </textarea></div>\n<pre>\n  1\n  <i>2</i></pre><a href=\"/\"><div>block in inline</div></a><p></p></body></html>"
+            ),
+            r#"<!DOCTYPE html>
+<html>
+  <head>
+    <title>a &lt; b</title>
+    <meta charset="UTF-8"/>
+    <style>p {
+  color: red;
+}</style>
+  </head>
+  <body>
+    <div class="x">
+      <p>Count: <b>5</b></p>
+      <!-- c -->
+      <span><a href="/">link</a> text</span><br><textarea> a
+  b </textarea>
+    </div>
+    <pre>
+  1
+  <i>2</i></pre>
+    <a href="/">
+      <div>block in inline</div>
+    </a>
+    <p></p>
+  </body>
+</html>"#
+        );
+    }
+
+    #[test]
+    fn test_pretty_malformed() {
+        assert_eq!(
+            pretty("<div><p>unclosed</div></span>tail"),
+            "<div>\n  <p>unclosed\n</div>\n</span>\ntail"
+        );
+    }
+}
@@ -0,0 +1,485 @@
+use crate::url::{scheme_of, split_srcset};
+use crate::{
+    AttributeContext, INVALID_CSS, Token, escape, is_void_element, sanitize_css, tokenize,
+    unescape, unescape_attribute,
+};
+use std::borrow::Cow;
+
+/// Elements removed by [`sanitize`] together with their contents,
+/// even when a [`Policy`] allows them.
+const DROPPED_WITH_CONTENTS: &[&str] = &[
+    "script",
+    "style",
+    "template",
+    "head",
+    "title",
+    "textarea",
+    "select",
+    "iframe",
+    "frame",
+    "frameset",
+    "object",
+    "embed",
+    "applet",
+    "noscript",
+    "noembed",
+    "noframes",
+    "xmp",
+    "plaintext",
+    "svg",
+    "math",
+];
+
+/// Allowlists of tags, attributes and URL schemes for [`sanitize`].
+///
+/// Names are matched ASCII case-insensitively.
+///
+/// ```
+/// use uibeam_html::Policy;
+///
+/// let policy = Policy::new()
+///     .allow_tags(["p", "a", "img"])
+///     .allow_attributes("a", ["href"])
+///     .allow_attributes("img", ["src", "alt"])
+///     .allow_global_attributes(["title"])
+///     .url_schemes(["https"]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Policy {
+    tags: Vec<Cow<'static, str>>,
+    /// `(tag, attribute)`s, `tag` is `None` for global attributes
+    attributes: Vec<(Option<Cow<'static, str>>, Cow<'static, str>)>,
+    url_schemes: Vec<Cow<'static, str>>,
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Policy {
+    /// A policy allowing only texts, with [`SAFE_URL_SCHEMES`](crate::SAFE_URL_SCHEMES)
+    /// as the allowed URL schemes.
+    pub fn new() -> Self {
+        Self {
+            tags: Vec::new(),
+            attributes: Vec::new(),
+            url_schemes: crate::SAFE_URL_SCHEMES
+                .iter()
+                .map(|s| Cow::Borrowed(*s))
+                .collect(),
+        }
+    }
+
+    /// Inline formatting without any attributes:
+    /// `b`, `strong`, `i`, `em`, `u`, `s`, `del`, `ins`, `mark`, `small`,
+    /// `sub`, `sup`, `code`, `br` and `p`.
+    pub fn basic_formatting() -> Self {
+        Self::new().allow_tags([
+            "b", "strong", "i", "em", "u", "s", "del", "ins", "mark", "small", "sub", "sup",
+            "code", "br", "p",
+        ])
+    }
+
+    /// [`basic_formatting`](Self::basic_formatting) plus links, images,
+    /// headings, lists, quotes, code blocks and tables.
+    pub fn relaxed() -> Self {
+        Self::basic_formatting()
+            .allow_tags([
+                "a",
+                "img",
+                "h1",
+                "h2",
+                "h3",
+                "h4",
+                "h5",
+                "h6",
+                "blockquote",
+                "q",
+                "cite",
+                "pre",
+                "kbd",
+                "abbr",
+                "hr",
+                "ul",
+                "ol",
+                "li",
+                "dl",
+                "dt",
+                "dd",
+                "figure",
+                "figcaption",
+                "table",
+                "caption",
+                "thead",
+                "tbody",
+                "tfoot",
+                "tr",
+                "th",
+                "td",
+                "div",
+                "span",
+            ])
+            .allow_global_attributes(["title", "lang", "dir"])
+            .allow_attributes("a", ["href"])
+            .allow_attributes("img", ["src", "srcset", "alt", "width", "height"])
+            .allow_attributes("blockquote", ["cite"])
+            .allow_attributes("q", ["cite"])
+            .allow_attributes("ol", ["start", "reversed"])
+            .allow_attributes("th", ["colspan", "rowspan", "scope"])
+            .allow_attributes("td", ["colspan", "rowspan"])
+    }
+
+    /// Allows the elements. Their contents are always kept, and
+    /// the tags of disallowed elements are just removed.
+    ///
+    /// Elements like `script`, `style` or `iframe` are never allowed
+    /// and removed together with their contents.
+    pub fn allow_tags(
+        mut self,
+        tags: impl IntoIterator<Item = impl Into<Cow<'static, str>>>,
+    ) -> Self {
+        self.tags.extend(tags.into_iter().map(Into::into));
+        self
+    }
+
+    /// Allows the attributes on the element `tag`.
+    ///
+    /// Event handlers like `onclick` and `srcdoc` are never allowed.
+    pub fn allow_attributes(
+        mut self,
+        tag: impl Into<Cow<'static, str>>,
+        attributes: impl IntoIterator<Item = impl Into<Cow<'static, str>>>,
+    ) -> Self {
+        let tag = tag.into();
+        self.attributes.extend(
+            attributes
+                .into_iter()
+                .map(|a| (Some(tag.clone()), a.into())),
+        );
+        self
+    }
+
+    /// Allows the attributes on all the allowed elements.
+    pub fn allow_global_attributes(
+        mut self,
+        attributes: impl IntoIterator<Item = impl Into<Cow<'static, str>>>,
+    ) -> Self {
+        self.attributes
+            .extend(attributes.into_iter().map(|a| (None, a.into())));
+        self
+    }
+
+    /// Replaces the allowed URL schemes. Relative URLs are always allowed.
+    pub fn url_schemes(
+        mut self,
+        schemes: impl IntoIterator<Item = impl Into<Cow<'static, str>>>,
+    ) -> Self {
+        self.url_schemes = schemes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    fn allows_tag(&self, tag: &str) -> bool {
+        !DROPPED_WITH_CONTENTS
+            .iter()
+            .any(|d| d.eq_ignore_ascii_case(tag))
+            && self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    fn allows_attribute(&self, tag: &str, name: &str) -> bool {
+        !matches!(
+            AttributeContext::of(name),
+            AttributeContext::Js | AttributeContext::Html
+        ) && self.attributes.iter().any(|(t, a)| {
+            a.eq_ignore_ascii_case(name) && t.as_ref().is_none_or(|t| t.eq_ignore_ascii_case(tag))
+        })
+    }
+
+    fn allows_url(&self, url: &str) -> bool {
+        scheme_of(url).is_none_or(|scheme| {
+            self.url_schemes
+                .iter()
+                .any(|s| s.eq_ignore_ascii_case(&scheme))
+        })
+    }
+
+    /// `None` if the attribute should be removed
+    fn sanitize_attribute_value<'v>(&self, name: &str, value: &'v str) -> Option<Cow<'v, str>> {
+        match AttributeContext::of(name) {
+            AttributeContext::Text => {
+                Some(Cow::Owned(escape(&unescape_attribute(value)).into_owned()))
+            }
+            context => {
+                // browsers see the value after resolving character references
+                let value = unescape_attribute(value);
+                let is_safe = match context {
+                    AttributeContext::Url => self.allows_url(&value),
+                    AttributeContext::UrlList => split_srcset(&value)
+                        .iter()
+                        .all(|(url, _)| self.allows_url(url)),
+                    AttributeContext::Css => sanitize_css(&value) != INVALID_CSS,
+                    _ => false,
+                };
+                is_safe.then(|| Cow::Owned(escape(&value).into_owned()))
+            }
+        }
+    }
+}
+
+/// Sanitizes untrusted HTML, like user posts, by the allowlists of `policy`.
+///
+/// - tags of disallowed elements are removed, keeping their contents
+/// - `script`, `style`, `iframe` and similar elements are removed
+///   together with their contents
+/// - disallowed attributes, event handlers like `onclick`, and URLs of
+///   disallowed schemes are removed
+/// - `style` attribute, if allowed, is removed when [`sanitize_css`]
+///   rejects it
+/// - comments and doctypes are removed
+/// - stray `<`, `>`, `&` and quotes are escaped, and unclosed elements
+///   are closed, so that the result never breaks the surrounding HTML
+///
+/// ```
+/// use uibeam_html::{sanitize, Policy};
+///
+/// assert_eq!(
+///     sanitize(
+///         r#"<p onclick="steal()">Hi, <b>all</b>!<script>steal()</script></p><img src=x>"#,
+///         &Policy::basic_formatting(),
+///     ),
+///     "<p>Hi, <b>all</b>!</p>"
+/// );
+/// assert_eq!(
+///     sanitize(
+///         r#"<a href="javascript:steal()" title="home">home</a> <a href="/about">about"#,
+///         &Policy::relaxed(),
+///     ),
+///     r#"<a title="home">home</a> <a href="/about">about</a>"#
+/// );
+/// ```
+pub fn sanitize(html: &str, policy: &Policy) -> String {
+    let mut sanitized = String::with_capacity(html.len());
+    // lowercased names of the open allowed elements
+    let mut open = Vec::<String>::new();
+    // the element being removed with its contents, and its nesting depth
+    let mut dropping = None::<(&str, usize)>;
+
+    for token in tokenize(html) {
+        if let Some((name, depth)) = &mut dropping {
+            match token {
+                Token::StartTag(tag) if tag.name().eq_ignore_ascii_case(name) => *depth += 1,
+                Token::EndTag(tag) if tag.name().eq_ignore_ascii_case(name) => {
+                    *depth -= 1;
+                    if *depth == 0 {
+                        dropping = None;
+                    }
+                }
+                _ => (),
+            }
+            continue;
+        }
+
+        match token {
+            Token::Doctype(_) | Token::Comment(_) => (),
+            Token::Text(text) => sanitized.push_str(&escape(&unescape(text))),
+            Token::StartTag(tag) => {
+                let name = tag.name();
+                if DROPPED_WITH_CONTENTS
+                    .iter()
+                    .any(|d| d.eq_ignore_ascii_case(name))
+                {
+                    if !is_void_element(name) && !tag.is_self_closing() {
+                        dropping = Some((name, 1));
+                    }
+                    continue;
+                }
+                if !policy.allows_tag(name) {
+                    continue;
+                }
+
+                let name = name.to_ascii_lowercase();
+                sanitized.push('<');
+                sanitized.push_str(&name);
+                let mut seen = Vec::<&str>::new();
+                for (attribute, value) in tag.attributes() {
+                    // browsers take the first one of duplicated attributes
+                    if seen.iter().any(|s| s.eq_ignore_ascii_case(attribute)) {
+                        continue;
+                    }
+                    seen.push(attribute);
+                    if !policy.allows_attribute(&name, attribute) {
+                        continue;
+                    }
+                    match value {
+                        None => {
+                            sanitized.push(' ');
+                            sanitized.push_str(&attribute.to_ascii_lowercase());
+                        }
+                        Some(value) => {
+                            if let Some(value) = policy.sanitize_attribute_value(attribute, value) {
+                                sanitized.push(' ');
+                                sanitized.push_str(&attribute.to_ascii_lowercase());
+                                sanitized.push_str("=\"");
+                                sanitized.push_str(&value);
+                                sanitized.push('"');
+                            }
+                        }
+                    }
+                }
+                sanitized.push('>');
+
+                if !is_void_element(&name) {
+                    open.push(name);
+                }
+            }
+            Token::EndTag(tag) => {
+                if let Some(i) = open
+                    .iter()
+                    .rposition(|o| o.eq_ignore_ascii_case(tag.name()))
+                {
+                    for name in open.drain(i..).rev() {
+                        sanitized.push_str("</");
+                        sanitized.push_str(&name);
+                        sanitized.push('>');
+                    }
+                }
+            }
+        }
+    }
+
+    for name in open.into_iter().rev() {
+        sanitized.push_str("</");
+        sanitized.push_str(&name);
+        sanitized.push('>');
+    }
+    sanitized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_basic_formatting() {
+        let policy = Policy::basic_formatting();
+        for (html, expected) in [
+            ("plain text", "plain text"),
+            ("<p>a <B>b</B> <i>c</i></p>", "<p>a <b>b</b> <i>c</i></p>"),
+            ("a<br/>b", "a<br>b"),
+            ("<div><p>unwrapped</p></div>", "<p>unwrapped</p>"),
+            (
+                "<p title=x class=y>no attributes</p>",
+                "<p>no attributes</p>",
+            ),
+            ("<a href=/x>link</a>", "link"),
+            ("<p>unclosed <b>bold", "<p>unclosed <b>bold</b></p>"),
+            (
+                "<b>overlapped <i>tags</b></i>",
+                "<b>overlapped <i>tags</i></b>",
+            ),
+            ("stray </p> end tag", "stray  end tag"),
+            ("1 < 2 && 3 > 2", "1 &lt; 2 &amp;&amp; 3 &gt; 2"),
+            (
+                "&lt;kept&gt; &#60; &#x3c; &copy;",
+                "&lt;kept&gt; &lt; &lt; \u{A9}",
+            ),
+            ("say \"hi\"", "say &#34;hi&#34;"),
+            ("a<!-- comment -->b<!DOCTYPE html>", "ab"),
+            ("<script>alert(1)</script>text", "text"),
+            ("<SCRIPT>alert('</b>')</SCRIPT>text", "text"),
+            ("<style>p { color: red }</style>text", "text"),
+            (
+                "<svg><svg><script>x</script></svg><b>in svg</b></svg>text",
+                "text",
+            ),
+            ("<iframe src=x>fallback</iframe>text", "text"),
+            ("<embed src=x.swf>text", "text"),
+            ("<textarea></textarea><b>text</b>", "<b>text</b>"),
+        ] {
+            assert_eq!(sanitize(html, &policy), expected, "{html:?}");
+        }
+    }
+
+    #[test]
+    fn test_sanitize_relaxed() {
+        let policy = Policy::relaxed();
+        for (html, expected) in [
+            (
+                r#"<a href="https://example.com" TITLE='t'>ok</a>"#,
+                r#"<a href="https://example.com" title="t">ok</a>"#,
+            ),
+            (
+                r#"<a href="/a?x=1&amp;y=2">ok</a>"#,
+                r#"<a href="/a?x=1&amp;y=2">ok</a>"#,
+            ),
+            (r#"<a href="javascript:alert(1)">x</a>"#, "<a>x</a>"),
+            (r#"<a href=" JaVa&#x09;Script:alert(1)">x</a>"#, "<a>x</a>"),
+            (r#"<a href="&#106;avascript:alert(1)">x</a>"#, "<a>x</a>"),
+            (r#"<a href="&#106avascript:alert(1)">x</a>"#, "<a>x</a>"),
+            (r#"<a href="javascript&colon;alert(1)">x</a>"#, "<a>x</a>"),
+            (r#"<a href="data:text/html,x">x</a>"#, "<a>x</a>"),
+            (
+                r#"<a href="/a" href="javascript:x">x</a>"#,
+                r#"<a href="/a">x</a>"#,
+            ),
+            (
+                r#"<a href="/a" onclick="alert(1)" target=_top>x</a>"#,
+                r#"<a href="/a">x</a>"#,
+            ),
+            (
+                r#"<img src="a.png" alt='"quoted"'>"#,
+                r#"<img src="a.png" alt="&#34;quoted&#34;">"#,
+            ),
+            (r#"<img srcset="a.png 1x, javascript:x 2x">"#, "<img>"),
+            (r#"<img src=x onerror=alert(1)>"#, r#"<img src="x">"#),
+            (r#"<p style="color: red">x</p>"#, "<p>x</p>"),
+            (
+                r#"<ol reversed start=3><li>x</ol>"#,
+                r#"<ol reversed start="3"><li>x</li></ol>"#,
+            ),
+        ] {
+            assert_eq!(sanitize(html, &policy), expected, "{html:?}");
+        }
+    }
+
+    #[test]
+    fn test_sanitize_custom_policy() {
+        let policy = Policy::new()
+            .allow_tags(["span", "a", "script", "iframe"])
+            .allow_attributes("SPAN", ["style", "onclick", "onmozfullscreenchange"])
+            .allow_attributes("a", ["href", "srcdoc"])
+            .url_schemes(["myapp"]);
+        for (html, expected) in [
+            (
+                r#"<span style="color: red" onclick="x()">x</span>"#,
+                r#"<span style="color: red">x</span>"#,
+            ),
+            (
+                r#"<span onmozfullscreenchange="x()" onbeforescriptexecute="y()">x</span>"#,
+                "<span>x</span>",
+            ),
+            (
+                r#"<span style="background: url(javascript:x)">x</span>"#,
+                "<span>x</span>",
+            ),
+            (
+                r#"<span style="width: &#101;xpression(x)">x</span>"#,
+                "<span>x</span>",
+            ),
+            (
+                r#"<a href="myapp://home" srcdoc="x">x</a>"#,
+                r#"<a href="myapp://home">x</a>"#,
+            ),
+            (r#"<a href="https://example.com">x</a>"#, "<a>x</a>"),
+            ("<script>x</script><iframe>y</iframe>text", "text"),
+        ] {
+            assert_eq!(sanitize(html, &policy), expected, "{html:?}");
+        }
+
+        assert_eq!(
+            sanitize("<p><b>text only</b></p>", &Policy::new()),
+            "text only"
+        );
+    }
+}
@@ -0,0 +1,349 @@
+/// A lexical token of HTML, borrowing from the input.
+///
+/// This is intended to read HTML generated by UIBeam itself
+/// (or reasonably well-formed hand-written HTML), not to be
+/// a complete implementation of the HTML5 tokenization algorithm.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Token<'h> {
+    /// `<!DOCTYPE html>` or any other `<!...>` declaration
+    Doctype(&'h str),
+    /// `<!-- ... -->`
+    Comment(&'h str),
+    /// text content, still HTML-escaped as it is in the input
+    Text(&'h str),
+    /// `<name ...>` or `<name ... />`
+    StartTag(Tag<'h>),
+    /// `</name>`
+    EndTag(Tag<'h>),
+}
+
+impl<'h> Token<'h> {
+    /// The original source of this token.
+    pub fn as_str(&self) -> &'h str {
+        match self {
+            Token::Doctype(raw) | Token::Comment(raw) | Token::Text(raw) => raw,
+            Token::StartTag(tag) | Token::EndTag(tag) => tag.raw,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tag<'h> {
+    raw: &'h str,
+    name: &'h str,
+    attributes: &'h str,
+    self_closing: bool,
+}
+
+impl<'h> Tag<'h> {
+    /// The original source of this tag, e.g. `<a href="/">`.
+    pub fn as_str(&self) -> &'h str {
+        self.raw
+    }
+
+    /// The tag name as it is in the input (not normalized to lowercase).
+    pub fn name(&self) -> &'h str {
+        self.name
+    }
+
+    /// Whether the tag ends with `/>`.
+    pub fn is_self_closing(&self) -> bool {
+        self.self_closing
+    }
+
+    /// Iterates over `(name, value)` pairs of the attributes,
+    /// where `value` is `None` for a boolean attribute like `checked`.
+    ///
+    /// Values are unquoted but still HTML-escaped as they are in the input.
+    pub fn attributes(&self) -> Attributes<'h> {
+        Attributes {
+            rest: self.attributes,
+        }
+    }
+
+    /// Looks up an attribute by ASCII case-insensitive name.
+    ///
+    /// Returns `Some(None)` when the attribute exists without a value.
+    pub fn attribute(&self, name: &str) -> Option<Option<&'h str>> {
+        self.attributes()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+}
+
+/// Iterator over the attributes of a [`Tag`].
+#[derive(Clone)]
+pub struct Attributes<'h> {
+    rest: &'h str,
+}
+impl<'h> Iterator for Attributes<'h> {
+    type Item = (&'h str, Option<&'h str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self
+            .rest
+            .trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+        if rest.is_empty() {
+            self.rest = rest;
+            return None;
+        }
+
+        let name_end = rest
+            .find(|c: char| c.is_ascii_whitespace() || matches!(c, '=' | '/'))
+            .unwrap_or(rest.len());
+        let (name, rest) = rest.split_at(name_end);
+
+        let after_name = rest.trim_start_matches(|c: char| c.is_ascii_whitespace());
+        let Some(after_eq) = after_name.strip_prefix('=') else {
+            self.rest = rest;
+            return Some((name, None));
+        };
+        let after_eq = after_eq.trim_start_matches(|c: char| c.is_ascii_whitespace());
+
+        let (value, rest) = match after_eq.as_bytes().first() {
+            Some(quote @ (b'"' | b'\'')) => {
+                let body = &after_eq[1..];
+                match body.find(*quote as char) {
+                    Some(close) => (&body[..close], &body[close + 1..]),
+                    None => (body, ""),
+                }
+            }
+            _ => {
+                let end = after_eq
+                    .find(|c: char| c.is_ascii_whitespace())
+                    .unwrap_or(after_eq.len());
+                after_eq.split_at(end)
+            }
+        };
+        self.rest = rest;
+        Some((name, Some(value)))
+    }
+}
+
+/// Whether the element is a *void element*, that never has any content
+/// nor end tag, like `<br>` or `<meta>`.
+pub fn is_void_element(name: &str) -> bool {
+    const VOID_ELEMENTS: &[&str] = &[
+        "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source",
+        "track", "wbr",
+    ];
+    VOID_ELEMENTS.iter().any(|v| v.eq_ignore_ascii_case(name))
+}
+
+/// Whether the content of the element is not markup but raw text,
+/// like `<script>` or `<textarea>`.
+pub fn is_raw_text_element(name: &str) -> bool {
+    const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "textarea", "title"];
+    RAW_TEXT_ELEMENTS
+        .iter()
+        .any(|r| r.eq_ignore_ascii_case(name))
+}
+
+/// Splits HTML into a sequence of [`Token`]s.
+///
+/// Concatenating `as_str()` of all the tokens always reproduces the input.
+pub fn tokenize(html: &str) -> Tokens<'_> {
+    Tokens {
+        html,
+        pos: 0,
+        raw_text_of: None,
+    }
+}
+
+/// Iterator returned by [`tokenize`].
+pub struct Tokens<'h> {
+    html: &'h str,
+    pos: usize,
+    /// set just after a start tag of a raw text element
+    raw_text_of: Option<&'h str>,
+}
+
+impl<'h> Iterator for Tokens<'h> {
+    type Item = Token<'h>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.html[self.pos..];
+        if rest.is_empty() {
+            return None;
+        }
+
+        if let Some(element) = self.raw_text_of.take() {
+            let end = find_end_tag(rest, element).unwrap_or(rest.len());
+            if end > 0 {
+                self.pos += end;
+                return Some(Token::Text(&rest[..end]));
+            }
+        }
+
+        let (token, len) = if let Some(comment) = rest.strip_prefix("<!--") {
+            let len = comment.find("-->").map_or(rest.len(), |i| 4 + i + 3);
+            (Token::Comment(&rest[..len]), len)
+        } else if rest.starts_with("<!") {
+            let len = rest.find('>').map_or(rest.len(), |i| i + 1);
+            (Token::Doctype(&rest[..len]), len)
+        } else if let Some((tag, len)) = rest.strip_prefix("</").and_then(|_| lex_tag(rest, 2)) {
+            (Token::EndTag(tag), len)
+        } else if let Some((tag, len)) = lex_tag(rest, 1) {
+            if !tag.self_closing && is_raw_text_element(tag.name) {
+                self.raw_text_of = Some(tag.name);
+            }
+            (Token::StartTag(tag), len)
+        } else {
+            // a text continues until the next `<` that starts a tag or something
+            let mut len = rest.chars().next().map_or(1, char::len_utf8);
+            while let Some(i) = rest[len..].find('<') {
+                len += i;
+                if starts_markup(&rest[len..]) {
+                    break;
+                }
+                len += 1;
+            }
+            if !rest[len..].starts_with('<') {
+                len = rest.len();
+            }
+            (Token::Text(&rest[..len]), len)
+        };
+
+        self.pos += len;
+        Some(token)
+    }
+}
+
+fn starts_markup(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    match bytes.get(1) {
+        Some(b'!') => true,
+        Some(b'/') => bytes.get(2).is_some_and(u8::is_ascii_alphabetic),
+        Some(b) => b.is_ascii_alphabetic(),
+        None => false,
+    }
+}
+
+/// lexes `<name ...>` (`name_start` = 1) or `</name ...>` (`name_start` = 2)
+fn lex_tag(s: &str, name_start: usize) -> Option<(Tag<'_>, usize)> {
+    if !s.starts_with('<') || !s.as_bytes().get(name_start)?.is_ascii_alphabetic() {
+        return None;
+    }
+
+    let name_end = name_start
+        + s[name_start..]
+            .find(|c: char| c.is_ascii_whitespace() || matches!(c, '/' | '>'))
+            .unwrap_or(s.len() - name_start);
+
+    let mut quote = None;
+    let mut close = None;
+    for (i, b) in s.bytes().enumerate().skip(name_end) {
+        match (quote, b) {
+            (None, b'"' | b'\'') => quote = Some(b),
+            (Some(q), _) if q == b => quote = None,
+            (None, b'>') => {
+                close = Some(i);
+                break;
+            }
+            _ => (),
+        }
+    }
+    let close = close?;
+
+    let self_closing = s[name_end..close].trim_end().ends_with('/');
+    Some((
+        Tag {
+            raw: &s[..close + 1],
+            name: &s[name_start..name_end],
+            attributes: &s[name_end..close],
+            self_closing,
+        },
+        close + 1,
+    ))
+}
+
+fn find_end_tag(s: &str, name: &str) -> Option<usize> {
+    let mut offset = 0;
+    while let Some(i) = s[offset..].find("</") {
+        let start = offset + i;
+        let after = &s[start + 2..];
+        if after.len() >= name.len()
+            && after.as_bytes()[..name.len()].eq_ignore_ascii_case(name.as_bytes())
+            && after[name.len()..]
+                .chars()
+                .next()
+                .is_none_or(|c| c.is_ascii_whitespace() || matches!(c, '/' | '>'))
+        {
+            return Some(start);
+        }
+        offset = start + 2;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        let html = r#"<!DOCTYPE html><html lang="en"><head><title>a < b</title><meta charset="UTF-8"/></head><body><!-- comment --><p class='x y' hidden>1 < 2 &amp; 3</p><script>if (a</b) {}</script><br>こんにちは</body></html>"#;
+
+        let tokens = tokenize(html).collect::<Vec<_>>();
+        assert_eq!(tokens.iter().map(Token::as_str).collect::<String>(), html);
+
+        let summary = tokens
+            .iter()
+            .map(|t| match t {
+                Token::Doctype(_) => "!".to_string(),
+                Token::Comment(_) => "#".to_string(),
+                Token::Text(text) => format!("'{text}'"),
+                Token::StartTag(tag) if tag.is_self_closing() => format!("<{}/>", tag.name()),
+                Token::StartTag(tag) => format!("<{}>", tag.name()),
+                Token::EndTag(tag) => format!("</{}>", tag.name()),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [
+                "!",
+                "<html>",
+                "<head>",
+                "<title>",
+                "'a < b'",
+                "</title>",
+                "<meta/>",
+                "</head>",
+                "<body>",
+                "#",
+                "<p>",
+                "'1 < 2 &amp; 3'",
+                "</p>",
+                "<script>",
+                "'if (a</b) {}'",
+                "</script>",
+                "<br>",
+                "'こんにちは'",
+                "</body>",
+                "</html>",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_attributes() {
+        let Some(Token::StartTag(tag)) =
+            tokenize(r#"<input type="checkbox" checked data-x='a "b"' value=1 />"#).next()
+        else {
+            panic!("expected a start tag")
+        };
+        assert!(tag.is_self_closing());
+        assert_eq!(
+            tag.attributes().collect::<Vec<_>>(),
+            [
+                ("type", Some("checkbox")),
+                ("checked", None),
+                ("data-x", Some(r#"a "b""#)),
+                ("value", Some("1")),
+            ]
+        );
+        assert_eq!(tag.attribute("TYPE"), Some(Some("checkbox")));
+        assert_eq!(tag.attribute("checked"), Some(None));
+        assert_eq!(tag.attribute("name"), None);
+    }
+}
@@ -0,0 +1,200 @@
+mod entities;
+
+use entities::ENTITIES;
+use std::borrow::Cow;
+
+/// Decodes character references in a text, like `&amp;`, `&hellip;`,
+/// `&#39;` or `&#x1F600;`, in the same way as browsers do.
+///
+/// This covers all the named character references of HTML5, including
+/// legacy ones without the trailing `;` like `&copy`, and numeric ones
+/// with the replacements of the spec (e.g. `&#0;` into U+FFFD).
+/// An unknown reference is kept as it is.
+///
+/// When the input string does not contain any `&`,
+/// it just returns a borrowed reference to the original string.
+///
+/// ```
+/// use uibeam_html::unescape;
+///
+/// assert_eq!(unescape("Tom &amp; Jerry"), "Tom & Jerry");
+/// assert_eq!(unescape("&lt;p&gt;&hellip;&lt;/p&gt;"), "<p>…</p>");
+/// assert_eq!(unescape("&#34;quoted&#x22;"), "\"quoted\"");
+/// assert_eq!(unescape("&copy 2025 &unknown;"), "© 2025 &unknown;");
+/// ```
+pub fn unescape(s: &str) -> Cow<'_, str> {
+    unescape_in(s, false)
+}
+
+/// [`unescape`] for an attribute value: a legacy named reference without
+/// the trailing `;` is kept when followed by an alphanumeric or `=`,
+/// like `&copy` in `href="?a=1&copy=2"`.
+///
+/// ```
+/// use uibeam_html::unescape_attribute;
+///
+/// assert_eq!(unescape_attribute("/?a=1&amp;copy=2"), "/?a=1&copy=2");
+/// assert_eq!(unescape_attribute("/?a=1&copy=2"), "/?a=1&copy=2");
+/// assert_eq!(unescape_attribute("&copy 2025"), "© 2025");
+/// ```
+pub fn unescape_attribute(s: &str) -> Cow<'_, str> {
+    unescape_in(s, true)
+}
+
+fn unescape_in(s: &str, in_attribute: bool) -> Cow<'_, str> {
+    let Some(first_amp) = s.find('&') else {
+        return Cow::Borrowed(s);
+    };
+
+    let mut unescaped = String::with_capacity(s.len());
+    unescaped.push_str(&s[..first_amp]);
+    let mut rest = &s[first_amp..];
+    while let Some(reference) = rest.strip_prefix('&') {
+        let consumed = match reference.strip_prefix('#') {
+            Some(numeric) => decode_numeric(numeric).map(|(c, len)| {
+                unescaped.push(c);
+                1 + len
+            }),
+            None => decode_named(reference, in_attribute).map(|(decoded, len)| {
+                unescaped.push_str(decoded);
+                len
+            }),
+        };
+        let consumed = consumed.unwrap_or_else(|| {
+            unescaped.push('&');
+            0
+        });
+        rest = &reference[consumed..];
+
+        let next_amp = rest.find('&').unwrap_or(rest.len());
+        unescaped.push_str(&rest[..next_amp]);
+        rest = &rest[next_amp..];
+    }
+    Cow::Owned(unescaped)
+}
+
+/// `(decoded, length of the name)` for `&{name}...`
+fn decode_named(reference: &str, in_attribute: bool) -> Option<(&'static str, usize)> {
+    /// the longest name of legacy references without `;`
+    const MAX_LEGACY_LEN: usize = 6;
+
+    let name_len = reference
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(reference.len());
+    if name_len == 0 {
+        return None;
+    }
+
+    if reference[name_len..].starts_with(';')
+        && let Some(decoded) = lookup(&reference[..=name_len])
+    {
+        return Some((decoded, name_len + 1));
+    }
+
+    // the longest legacy reference
+    (2..=name_len.min(MAX_LEGACY_LEN)).rev().find_map(|len| {
+        let decoded = lookup(&reference[..len])?;
+        let next = reference[len..].chars().next();
+        match in_attribute && next.is_some_and(|c| c.is_ascii_alphanumeric() || c == '=') {
+            true => None,
+            false => Some((decoded, len)),
+        }
+    })
+}
+
+fn lookup(name: &str) -> Option<&'static str> {
+    ENTITIES
+        .binary_search_by(|(n, _)| n.as_bytes().cmp(name.as_bytes()))
+        .ok()
+        .map(|i| ENTITIES[i].1)
+}
+
+/// `(decoded, length)` for `&#{numeric}...`
+fn decode_numeric(numeric: &str) -> Option<(char, usize)> {
+    let (digits, radix, prefix_len) = match numeric.strip_prefix(['x', 'X']) {
+        Some(hex) => (hex, 16, 1),
+        None => (numeric, 10, 0),
+    };
+    let digits_len = digits
+        .find(|c: char| !c.is_digit(radix))
+        .unwrap_or(digits.len());
+    if digits_len == 0 {
+        return None;
+    }
+
+    let code = digits[..digits_len].chars().fold(0_u32, |code, d| {
+        code.saturating_mul(radix)
+            .saturating_add(d.to_digit(radix).unwrap())
+    });
+    let c = match code {
+        0 => char::REPLACEMENT_CHARACTER,
+        0x80..=0x9f => WINDOWS_1252[(code - 0x80) as usize],
+        _ => char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER),
+    };
+    let semicolon_len = digits[digits_len..].starts_with(';') as usize;
+    Some((c, prefix_len + digits_len + semicolon_len))
+}
+
+/// characters for the C1 control codes `0x80..=0x9f`, as the spec replaces
+/// numeric references to them as windows-1252
+const WINDOWS_1252: [char; 32] = [
+    '\u{20AC}', '\u{81}', '\u{201A}', '\u{192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{2C6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8D}', '\u{17D}', '\u{8F}',
+    '\u{90}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{2DC}', '\u{2122}', '\u{161}', '\u{203A}', '\u{153}', '\u{9D}', '\u{17E}', '\u{178}',
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entities_are_sorted() {
+        assert!(
+            ENTITIES
+                .windows(2)
+                .all(|w| w[0].0.as_bytes() < w[1].0.as_bytes())
+        );
+        assert_eq!(ENTITIES.len(), 2231);
+    }
+
+    #[test]
+    fn test_unescape() {
+        for (input, expected) in [
+            ("", ""),
+            ("no references", "no references"),
+            ("&", "&"),
+            ("& &; &#; &#x; &#xg;", "& &; &#; &#x; &#xg;"),
+            ("&amp;&lt;&gt;&quot;&apos;", "&<>\"'"),
+            ("&AMP;&LT &GT", "&< >"),
+            ("&NotNestedGreaterGreater;", "\u{2AA2}\u{338}"),
+            ("&CounterClockwiseContourIntegral;", "\u{2233}"),
+            ("&notin; &notit;", "\u{2209} \u{AC}it;"),
+            ("&copy2025", "\u{A9}2025"),
+            ("&unknown; &amp", "&unknown; &"),
+            ("&#65;&#x41;&#X41;&#0065", "AAAA"),
+            ("&#128512;&#x1f600;", "\u{1F600}\u{1F600}"),
+            (
+                "&#0;&#xD800;&#x110000;&#99999999999;",
+                "\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}",
+            ),
+            ("&#x80;&#150;&#x81;", "\u{20AC}\u{2013}\u{81}"),
+            ("あ&amp;い&#12358;", "あ&いう"),
+        ] {
+            assert_eq!(unescape(input), expected, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_unescape_attribute() {
+        for (input, expected) in [
+            ("&copy=2", "&copy=2"),
+            ("&copya", "&copya"),
+            ("&copy;a", "\u{A9}a"),
+            ("&copy-a", "\u{A9}-a"),
+            ("&#106avascript", "javascript"),
+        ] {
+            assert_eq!(unescape_attribute(input), expected, "{input:?}");
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use crate::AttributeContext;
+use std::borrow::Cow;
+
+/// The value that a dangerous URL is replaced with.
+///
+/// `about:invalid` never navigates anywhere nor loads anything,
+/// and `#uibeam` tells where it comes from.
+pub const INVALID_URL: &str = "about:invalid#uibeam";
+
+/// URL schemes allowed by [`sanitize_url`]. Relative URLs are always allowed.
+pub const SAFE_URL_SCHEMES: &[&str] = &["http", "https", "mailto", "tel"];
+
+/// Whether the attribute takes a URL (or a list of URLs for `srcset`).
+///
+/// See [`AttributeContext`] for the classification.
+pub fn is_url_attribute(name: &str) -> bool {
+    matches!(
+        AttributeContext::of(name),
+        AttributeContext::Url | AttributeContext::UrlList
+    )
+}
+
+/// Neutralizes a URL with a dangerous scheme like `javascript:`
+/// into [`INVALID_URL`].
+///
+/// A URL is kept as it is when it is relative or its scheme is
+/// one of [`SAFE_URL_SCHEMES`]. The scheme is checked in the same way
+/// as browsers do, that is, ignoring leading control characters and
+/// spaces, and tabs or newlines within the scheme.
+///
+/// Note that the result is not HTML-escaped.
+///
+/// ```
+/// use uibeam_html::{sanitize_url, INVALID_URL};
+///
+/// assert_eq!(sanitize_url("/users?id=1"), "/users?id=1");
+/// assert_eq!(sanitize_url("https://example.com"), "https://example.com");
+/// assert_eq!(sanitize_url("javascript:alert(1)"), INVALID_URL);
+/// assert_eq!(sanitize_url(" Java\tScript:alert(1)"), INVALID_URL);
+/// ```
+pub fn sanitize_url(url: &str) -> Cow<'_, str> {
+    match scheme_of(url) {
+        Some(scheme)
+            if !SAFE_URL_SCHEMES
+                .iter()
+                .any(|s| s.eq_ignore_ascii_case(&scheme)) =>
+        {
+            Cow::Borrowed(INVALID_URL)
+        }
+        _ => Cow::Borrowed(url),
+    }
+}
+
+/// The scheme of `url` as browsers see it, `None` for a relative URL.
+pub(crate) fn scheme_of(url: &str) -> Option<String> {
+    let mut scheme = String::new();
+    for c in url
+        .trim_start_matches(|c: char| c <= ' ')
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+    {
+        match c {
+            ':' => return Some(scheme),
+            // a relative URL
+            '/' | '?' | '#' => return None,
+            _ => scheme.push(c),
+        }
+    }
+    None
+}
+
+/// Applies [`sanitize_url`] to each image candidate of `srcset`.
+///
+/// ```
+/// use uibeam_html::sanitize_srcset;
+///
+/// assert_eq!(
+///     sanitize_srcset("/a.png 1x, javascript:alert(1) 2x"),
+///     "/a.png 1x, about:invalid#uibeam 2x"
+/// );
+/// ```
+pub fn sanitize_srcset(srcset: &str) -> Cow<'_, str> {
+    let candidates = split_srcset(srcset);
+    if candidates
+        .iter()
+        .all(|(url, _)| matches!(sanitize_url(url), Cow::Borrowed(s) if s == *url))
+    {
+        return Cow::Borrowed(srcset);
+    }
+
+    let mut sanitized = String::with_capacity(srcset.len());
+    for (i, (url, descriptors)) in candidates.into_iter().enumerate() {
+        if i > 0 {
+            sanitized.push_str(", ");
+        }
+        sanitized.push_str(&sanitize_url(url));
+        if !descriptors.is_empty() {
+            sanitized.push(' ');
+            sanitized.push_str(descriptors);
+        }
+    }
+    Cow::Owned(sanitized)
+}
+
+/// `(url, descriptors)`s, following the parsing algorithm of `srcset`
+pub(crate) fn split_srcset(srcset: &str) -> Vec<(&str, &str)> {
+    let mut candidates = Vec::new();
+    let mut rest = srcset;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == ',');
+        if rest.is_empty() {
+            return candidates;
+        }
+
+        let url_end = rest
+            .find(|c: char| c.is_ascii_whitespace())
+            .unwrap_or(rest.len());
+        let url = &rest[..url_end];
+        if let Some(url) = url.strip_suffix(',') {
+            // no descriptors
+            candidates.push((url.trim_end_matches(','), ""));
+            rest = &rest[url_end..];
+            continue;
+        }
+
+        let after_url = &rest[url_end..];
+        let descriptors_end = after_url.find(',').unwrap_or(after_url.len());
+        candidates.push((url, after_url[..descriptors_end].trim()));
+        rest = &after_url[descriptors_end..];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_url() {
+        for (url, expected) in [
+            ("", ""),
+            ("/", "/"),
+            ("./a:b", "./a:b"),
+            ("?q=javascript:x", "?q=javascript:x"),
+            ("#javascript:x", "#javascript:x"),
+            ("HTTPS://example.com", "HTTPS://example.com"),
+            ("mailto:a@example.com", "mailto:a@example.com"),
+            ("tel:+1-555", "tel:+1-555"),
+            ("javascript:alert(1)", INVALID_URL),
+            ("JaVaScRiPt:alert(1)", INVALID_URL),
+            ("\x01 javascript:alert(1)", INVALID_URL),
+            ("java\nscript:alert(1)", INVALID_URL),
+            ("vbscript:msgbox", INVALID_URL),
+            ("data:text/html,<script>alert(1)</script>", INVALID_URL),
+        ] {
+            assert_eq!(sanitize_url(url), expected, "{url:?}");
+        }
+    }
+
+    #[test]
+    fn test_sanitize_srcset() {
+        for (srcset, expected) in [
+            ("a.png", "a.png"),
+            ("a.png 1x, b.png 2x", "a.png 1x, b.png 2x"),
+            (
+                "a.png, javascript:x 2x,b.png 100w ",
+                "a.png, about:invalid#uibeam 2x, b.png 100w",
+            ),
+        ] {
+            assert_eq!(sanitize_srcset(srcset), expected, "{srcset:?}");
+        }
+    }
+}
@@ -13,30 +13,91 @@ impl syn::parse::Parse for ClientArgs {
 }
 enum ClientArgument {
     Island,
+    Hydrate(HydrationStrategy),
 }
 impl syn::parse::Parse for ClientArgument {
     fn parse(p: &syn::parse::ParseBuffer) -> syn::Result<Self> {
         let ident = p.parse::<syn::Ident>()?;
         if ident == "island" {
             Ok(Self::Island)
+        } else if ident == "hydrate" {
+            let _eq = p.parse::<Token![=]>()?;
+            Ok(Self::Hydrate(p.parse()?))
         } else {
             Err(syn::Error::new(
                 ident.span(),
                 format!(
                     "unknown argument for #[client]: `{ident}`; \
-                    expected one of `#[client]` or `#[client(island)]`"
+                    expected one of `#[client]`, `#[client(island)]` or \
+                    `#[client(island, hydrate = \"...\")]`"
                 ),
             ))
         }
     }
 }
 
+/// when the runtime hydrates an island on browser
+enum HydrationStrategy {
+    /// as soon as the runtime is loaded (default)
+    Load,
+    /// when the island becomes visible in the viewport
+    Visible,
+    /// when the browser becomes idle
+    Idle,
+    /// on the first interaction with the island
+    Interaction,
+    /// when the media query matches
+    Media(String),
+}
+impl syn::parse::Parse for HydrationStrategy {
+    fn parse(p: &syn::parse::ParseBuffer) -> syn::Result<Self> {
+        let lit = p.parse::<syn::LitStr>()?;
+        match &*lit.value() {
+            "load" => Ok(Self::Load),
+            "visible" => Ok(Self::Visible),
+            "idle" => Ok(Self::Idle),
+            "interaction" => Ok(Self::Interaction),
+            other => match other.strip_prefix("media ").map(str::trim) {
+                Some(query) if !query.is_empty() => Ok(Self::Media(query.to_owned())),
+                _ => Err(syn::Error::new(
+                    lit.span(),
+                    format!(
+                        "unknown hydration strategy: `{other}`; expected one of \
+                        `load`, `visible`, `idle`, `interaction` or `media {{query}}` \
+                        like `media (max-width: 600px)`"
+                    ),
+                )),
+            },
+        }
+    }
+}
+
 pub(super) fn expand(args: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
     let args = syn::parse2::<ClientArgs>(args)?;
     let impl_beam = syn::parse2::<syn::ItemImpl>(input)?;
 
     let is_island_boundary = args.0.iter().any(|a| matches!(a, ClientArgument::Island));
 
+    let strategy = args.0.into_iter().find_map(|a| match a {
+        ClientArgument::Hydrate(strategy) => Some(strategy),
+        _ => None,
+    });
+    if strategy.is_some() && !is_island_boundary {
+        return Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "`hydrate = \"...\"` is only for island boundary: `#[client(island, hydrate = \"...\")]`",
+        ));
+    }
+    let strategy_attributes = match strategy {
+        None | Some(HydrationStrategy::Load) => quote! {},
+        Some(HydrationStrategy::Visible) => quote! { data-uibeam-strategy="visible" },
+        Some(HydrationStrategy::Idle) => quote! { data-uibeam-strategy="idle" },
+        Some(HydrationStrategy::Interaction) => quote! { data-uibeam-strategy="interaction" },
+        Some(HydrationStrategy::Media(query)) => {
+            quote! { data-uibeam-strategy="media" data-uibeam-media=#query }
+        }
+    };
+
     let self_ty = impl_beam.self_ty.clone();
     let self_name = match &*self_ty {
         syn::Type::Path(p) => &p.path.segments.last().unwrap().ident,
@@ -49,6 +110,11 @@ pub(super) fn expand(args: TokenStream, input: TokenStream) -> syn::Result<Token
     };
     let hydrater_name = format_ident!("__uibeam_hydrate_{self_name}__");
     let hydrater_name_str = syn::LitStr::new(&hydrater_name.to_string(), hydrater_name.span());
+    // the crate whose assets hydrate this island, see `uibeam::assets::add_islands`
+    let crate_name_str = syn::LitStr::new(
+        &std::env::var("CARGO_CRATE_NAME").unwrap_or_default(),
+        self_name.span(),
+    );
 
     let impl_island_boundary = is_island_boundary.then(|| {
         let (impl_generics, ty_generics, where_clause) = impl_beam.generics.split_for_impl();
@@ -91,28 +157,30 @@ pub(super) fn expand(args: TokenStream, input: TokenStream) -> syn::Result<Token
         insert_client_directive_to_ui_macros(&mut stmts);
 
         fn_render.block = if is_island_boundary {
-            if crate::cfg_hydrate() {
-                parse_quote!({
-                    use ::uibeam::client_attribute as _;
-                    #(#stmts)*
-                })
-            } else {
-                parse_quote!({
-                    use ::uibeam::client_attribute as _;
-                    let props = ::uibeam::client::serialize_props(&self);
-                    let dry_ui = {
+            parse_quote!({
+                use ::uibeam::client_attribute as _;
+                ::uibeam::__hydrate_or_server! {
+                    {
                         #(#stmts)*
-                    };
-                    ::uibeam::UI! {
-                        <div
-                            data-uibeam-hydrater=#hydrater_name_str
-                            data-uibeam-props={props}
-                        >
-                            {dry_ui}
-                        </div>
                     }
-                })
-            }
+                    {
+                        let props = ::uibeam::client::serialize_props(&self);
+                        let dry_ui = {
+                            #(#stmts)*
+                        };
+                        ::uibeam::UI! {
+                            <div
+                                data-uibeam-hydrater=#hydrater_name_str
+                                data-uibeam-crate=#crate_name_str
+                                #strategy_attributes
+                                data-uibeam-props={props}
+                            >
+                                {dry_ui}
+                            </div>
+                        }
+                    }
+                }
+            })
         } else {
             parse_quote!({
                 use ::uibeam::client_attribute as _;
@@ -123,31 +191,36 @@ pub(super) fn expand(args: TokenStream, input: TokenStream) -> syn::Result<Token
         impl_beam
     };
 
-    let hydrater = (crate::cfg_hydrate() && is_island_boundary).then(|| {
+    let hydrater = is_island_boundary.then(|| {
         quote! {
-            #[doc(hidden)]
-            #[allow(unused, non_snake_case)]
-            pub mod #hydrater_name {
-                use super::#self_name;
-                use ::uibeam::client::wasm_bindgen;
-                use ::uibeam::client::wasm_bindgen::{JsCast, UnwrapThrowExt};
+            ::uibeam::__hydrate_or_server! { items
+                {
+                    #[doc(hidden)]
+                    #[allow(unused, non_snake_case)]
+                    pub mod #hydrater_name {
+                        use super::#self_name;
+                        use ::uibeam::client::wasm_bindgen;
+                        use ::uibeam::client::wasm_bindgen::{JsCast, UnwrapThrowExt};
 
-                #[doc(hidden)]
-                #[allow(unused, non_snake_case)]
-                #[wasm_bindgen::prelude::wasm_bindgen]
-                pub fn #hydrater_name(
-                    props: ::uibeam::client::js_sys::Object,
-                    container: ::uibeam::client::web_sys::Node,
-                ) {
-                    ::uibeam::client::hydrate(
-                        ::uibeam::client::VNode::new(
-                            ::uibeam::client::NodeType::component::<#self_name>(),
-                            props,
-                            const {vec![]},
-                        ),
-                        container,
-                    )
+                        #[doc(hidden)]
+                        #[allow(unused, non_snake_case)]
+                        #[wasm_bindgen::prelude::wasm_bindgen]
+                        pub fn #hydrater_name(
+                            props: ::uibeam::client::js_sys::Object,
+                            container: ::uibeam::client::web_sys::Node,
+                        ) {
+                            ::uibeam::client::hydrate(
+                                ::uibeam::client::VNode::new(
+                                    ::uibeam::client::NodeType::component::<#self_name>(),
+                                    props,
+                                    const {vec![]},
+                                ),
+                                container,
+                            )
+                        }
+                    }
                 }
+                {}
             }
         }
     });
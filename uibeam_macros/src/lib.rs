@@ -4,18 +4,6 @@
 mod client;
 mod ui;
 
-// hack to avoid generating codes that includes `#[cfg(hydrate)]`
-// and detect `hydrate` cfg in proc-macro context
-fn cfg_hydrate() -> bool {
-    // this LazyLock is just a performance improvement
-    // that avoids repeated read of an environment variable
-    static CACHE: std::sync::LazyLock<bool> = std::sync::LazyLock::new(|| {
-        // e.g. `--cfg hydrate`
-        std::env::var("RUSTFLAGS").is_ok_and(|it| it.contains("hydrate"))
-    });
-    *CACHE
-}
-
 /// # `UI!` - JSX-style template syntax
 ///
 /// > HTML completions and hovers are available by VSCode extension.\
@@ -92,6 +80,66 @@ fn cfg_hydrate() -> bool {
 /// }
 /// ```
 ///
+/// ### Static Templates
+///
+/// On the server side, `UI!` without any interpolations, Beams or `@style`s is
+/// compiled into a `const` of just one `&'static str`, so it's also usable as a `const`
+/// or `static` (not in hydration of client components). A `static` one is interpolated
+/// by reference:
+///
+/// ```ignore
+/// const NOT_FOUND: UI = UI! {
+///     <h1>"404 Not Found"</h1>
+/// };
+/// static BACK_TO_TOP: UI = UI! {
+///     <a href="#">"Back to top"</a>
+/// };
+///
+/// UI! {
+///     {NOT_FOUND}
+///     {&BACK_TO_TOP}
+/// }
+/// ```
+///
+/// ### Scoped Styles
+///
+/// `@style "...";` directives at the beginning of `UI!` declare CSS
+/// scoped to the elements in the `UI!` (including `children` passed to Beams from it).
+///
+/// A class `ub-{hash of the CSS}` is added to every element, and every selector
+/// of the CSS is rewritten to require the class. The CSS is hoisted into
+/// `<head>` just once per page by `shoot`, only when the `UI!` is actually rendered.
+///
+/// ```ignore
+/// UI! {
+///     @style r#"
+///         .card { padding: 1rem; }
+///         .card > h2 { font-size: 1.5rem; }
+///     "#;
+///     <div class="card">
+///         <h2>{title}</h2>
+///     </div>
+/// }
+/// ```
+///
+/// ### Minification
+///
+/// `@minify;` at the beginning of `UI!` collapses every run of whitespaces
+/// into a space in the string literals of its texts, at compile time.
+/// Contents of `<pre>`, `<textarea>`, `<script>` and `<style>` are kept as they are,
+/// and so are attribute values and `unsafe {...}`s.
+///
+/// ```ignore
+/// UI! {
+///     @minify;
+///     <p>"
+///         Hello,
+///         world!
+///     "</p>
+/// }
+/// // `<p> Hello, world! </p>`
+/// ```
+///
 /// ---
 ///
 ///
@@ -274,6 +322,25 @@ pub fn UI(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 ///    as its props, can NOT implement `Serialize` nor `Deserialize`, can NOT has `(island)`,
 ///    and can **only be used internally in `UI!` of another client component**.
 ///
+///    **NOTE**:
+///    An island is hydrated as soon as the runtime is loaded by default.
+///    `hydrate = "..."` delays it, and the Wasm is instantiated when the first island on the page is hydrated.
+///    This defers the Wasm compilation and the hydration work, not the downloads:
+///    `hydrate.js` is still loaded and the `.wasm` prefetched by the injected tags.
+///
+///    - `"visible"`: when the island becomes visible in the viewport
+///    - `"idle"`: when the browser becomes idle
+///    - `"interaction"`: on the first interaction (hover, pointer, focus or key) with the island,
+///      which is replayed to the hydrated island as a synthetic (untrusted) event
+///    - `"media {query}"`: when the media query matches, like `"media (min-width: 800px)"`
+///
+///    ```rust,ignore
+///    #[uibeam::client(island, hydrate = "visible")]
+///    impl Beam for Comments {
+///        /* ... */
+///    }
+///    ```
+///
 /// 4. Compile the lib crate into Wasm by `wasm-pack build` with **`RUSTFLAGS='--cfg hydrate'`** and **`--out-name hydrate --target web`**:
 ///
 ///     ```sh
@@ -290,8 +357,9 @@ pub fn UI(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 ///     ```
 ///   
 ///    **NOTE**:
-///    Both `hydrate` cfg (not feature!) and `hydrate` out-name are **required** here.
-///    This restriction may be relaxted in future versions.
+///    Both `hydrate` cfg (not feature!) and `hydrate` out-name are **required** here,
+///    unless the islands crate is registered by `uibeam::assets::add_islands` (see 5.).
+///    The cfg may also be given by `rustflags` in `.cargo/config.toml`, e.g. under `[target.wasm32-unknown-unknown]`.
 ///
 /// 5. Setup your server to serve the output directory (default: `pkg`) at **`/.uibeam`** route:
 ///
@@ -313,6 +381,19 @@ pub fn UI(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 ///
 ///    (as a result, the generated `{crate name}/pkg/hydrate.js` is served at `/.uibeam/hydrate.js` route,
 ///    which is automatically loaded together with corresponding .wasm file in the hydration step on browser.)
+///
+///    **NOTE**:
+///    To serve it at another route or from a CDN, call `uibeam::assets::set_base("https://cdn.example.com/islands")` at startup
+///    (or set `UIBEAM_ASSETS_BASE` environment variable at compile time).
+///
+///    **NOTE**:
+///    To build islands with another out-name, or to use islands of multiple crates on a page,
+///    register each islands crate at startup and serve its `pkg` at `/.uibeam/{crate name}`:
+///    `uibeam::assets::add_islands("my_islands", "my_islands")` (crate name, out-name).
+///
+///    **NOTE**:
+///    To serve the assets with content-hashed names for long-term caching, register the fingerprinted names
+///    from a manifest written by your build step: `uibeam::assets::load_manifest("./islands/pkg/manifest.json")`.
 ///    
 ///    **NOTE**:
 ///    Make sure that your server responds with **a complete HTML consist of one `<html></html>` containing your page contents**.
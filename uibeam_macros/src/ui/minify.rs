@@ -0,0 +1,104 @@
+//! `@minify;` - collapsing insignificant whitespaces in static texts
+
+use super::parse::{ContentPieceTokens, InterpolationTokens, NodeTokens};
+use syn::{Expr, ExprLit, Lit, LitStr};
+
+/// elements whose whitespaces are significant
+const PRESERVING: &[&str] = &["pre", "textarea", "script", "style"];
+
+/// Collapses every run of whitespaces into a space in static texts
+/// (string literals, and `{"..."}`) of `nodes`, except for the contents
+/// of `<pre>`, `<textarea>`, `<script>` and `<style>`.
+/// A run across adjacent static texts is collapsed too.
+///
+/// Attribute values and `unsafe {"..."}`s are kept as they are.
+pub(super) fn minify(nodes: &mut [NodeTokens]) {
+    for node in nodes {
+        minify_node(node);
+    }
+}
+
+fn minify_node(node: &mut NodeTokens) {
+    match node {
+        NodeTokens::EnclosingTag { tag, content, .. } => {
+            let tag = tag.to_string();
+            if !PRESERVING.iter().any(|p| p.eq_ignore_ascii_case(&tag)) {
+                minify_content(content);
+            }
+        }
+        NodeTokens::TextNode(content) => minify_content(content),
+        NodeTokens::Doctype { .. } | NodeTokens::SelfClosingTag { .. } => (),
+    }
+}
+
+fn minify_content(content: &mut [ContentPieceTokens]) {
+    // whether the previous piece is a static text ending with a whitespace,
+    // whose following whitespaces are also collapsed into it
+    let mut after_whitespace = false;
+    for piece in content {
+        match piece {
+            ContentPieceTokens::StaticText(text)
+            | ContentPieceTokens::Interpolation(InterpolationTokens {
+                _unsafe: None,
+                rust_expression:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(text),
+                        ..
+                    }),
+                ..
+            }) => {
+                *text = collapse(text, after_whitespace);
+                let value = text.value();
+                after_whitespace = value.ends_with(' ') || (value.is_empty() && after_whitespace);
+            }
+            ContentPieceTokens::Interpolation(_) => after_whitespace = false,
+            ContentPieceTokens::Node(node) => {
+                minify_node(node);
+                after_whitespace = false;
+            }
+        }
+    }
+}
+
+/// `lit` with its whitespaces collapsed, keeping whether it's a raw string.
+/// Leading whitespaces are removed if `trim_start`.
+fn collapse(lit: &LitStr, trim_start: bool) -> LitStr {
+    let value = lit.value();
+    let has_run = value
+        .as_bytes()
+        .windows(2)
+        .any(|w| is_whitespace(w[0] as char) && is_whitespace(w[1] as char));
+    let has_non_space = value.contains(|c: char| is_whitespace(c) && c != ' ');
+    if !(has_run || has_non_space || (trim_start && value.starts_with(' '))) {
+        return lit.clone();
+    }
+
+    let mut collapsed = String::with_capacity(value.len());
+    for c in value.chars() {
+        match is_whitespace(c) {
+            true if collapsed.ends_with(' ') || (trim_start && collapsed.is_empty()) => (),
+            true => collapsed.push(' '),
+            false => collapsed.push(c),
+        }
+    }
+
+    if lit.token().to_string().starts_with("r#") {
+        let hashes = (1..)
+            .map(|n| "#".repeat(n))
+            .find(|hashes| !collapsed.contains(&format!("\"{hashes}")))
+            .unwrap();
+        syn::parse_str::<LitStr>(&format!("r{hashes}\"{collapsed}\"{hashes}"))
+            .map(|mut raw| {
+                raw.set_span(lit.span());
+                raw
+            })
+            .unwrap_or_else(|_| LitStr::new(&collapsed, lit.span()))
+    } else {
+        LitStr::new(&collapsed, lit.span())
+    }
+}
+
+/// ASCII whitespace of HTML
+fn is_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\x0C' | '\r')
+}
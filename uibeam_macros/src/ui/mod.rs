@@ -1,32 +1,87 @@
+mod minify;
 mod parse;
+mod style;
 mod transform;
 
 use proc_macro2::TokenStream;
 use quote::quote;
 
+/// what `UI!` is expanded for
+#[derive(Clone, Copy)]
+pub(super) enum Target {
+    /// rendering HTML
+    Server,
+    /// building virtual DOM in `hydrate` cfg
+    #[cfg(feature = "client")]
+    Hydrate,
+}
+
+/// Expands `UI!` for both of the server and hydration, and then one of them
+/// is selected by `hydrate` cfg of `uibeam` crate, so that it's correct however
+/// the cfg is supplied (`RUSTFLAGS`, `.cargo/config.toml`, target-specific flags, ...).
 pub(super) fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let server = expand_with(input.clone(), true, Target::Server);
+
+    #[cfg(feature = "client")]
+    let hydrate = expand_with(input, true, Target::Hydrate);
+    #[cfg(not(feature = "client"))]
+    let hydrate = Err::<TokenStream, _>(syn::Error::new(
+        proc_macro2::Span::call_site(),
+        "`hydrate` cfg can not be activated without uibeam's `client` feature",
+    ));
+
+    let (server, hydrate) = match (server, hydrate) {
+        // mostly the same error for both
+        (Err(e), Err(_)) => return Err(e),
+        (server, hydrate) => (
+            server.unwrap_or_else(syn::Error::into_compile_error),
+            hydrate.unwrap_or_else(syn::Error::into_compile_error),
+        ),
+    };
+    Ok(quote! {
+        ::uibeam::__hydrate_or_server! {
+            { #hydrate }
+            { #server }
+        }
+    })
+}
+
+/// expands `children` of a Beam in `UI!` for the `target`.
+///
+/// `@style`s are inherited to scope the children, but not emitted again.
+pub(super) fn expand_children(input: TokenStream, target: Target) -> syn::Result<TokenStream> {
+    expand_with(input, false, target)
+}
+
+fn expand_with(input: TokenStream, emits_style: bool, target: Target) -> syn::Result<TokenStream> {
     let parse::UITokens {
-        #[allow(unused_variables)]
         directives,
-        #[allow(unused_mut)]
         mut nodes,
     } = syn::parse2(input)?;
 
-    if crate::cfg_hydrate() {
-        #[cfg(not(feature = "client"))]
-        {
-            Err(syn::Error::new(
-                proc_macro2::Span::call_site(),
-                "`hydrate` cfg can not be activated without uibeam's `client` feature",
-            ))
-        }
+    // children of a Beam are already minified as a part of the parent,
+    // except for ones in `<pre>` or such
+    let minifies = directives.iter().try_fold(false, |minifies, d| {
+        Ok::<_, syn::Error>(minifies | d.minify()?)
+    })?;
+    if minifies && emits_style {
+        minify::minify(&mut nodes);
+    }
+
+    match target {
         #[cfg(feature = "client")]
-        {
+        Target::Hydrate => {
+            let mut directives = directives;
+            if !directives.iter().any(|d| d.client()) {
+                // every `UI!` is rendered in islands in hydrate mode
+                directives.push(parse::Directive::new("client"));
+            }
+
             let uis = nodes
                 .clone()
                 .into_iter()
                 .map(|node| {
-                    let vdom_tokens = transform::hydrate::transform(node)?;
+                    let vdom_tokens = transform::hydrate::transform(&directives, node)?;
                     Ok(quote! {
                         ::uibeam::UI::new_unchecked(#vdom_tokens)
                     })
@@ -37,51 +92,104 @@ pub(super) fn expand(input: TokenStream) -> syn::Result<TokenStream> {
                 <::uibeam::UI>::from_iter([#(#uis),*])
             })
         }
-    } else {
-        if nodes
-            .first()
-            .is_some_and(|node| matches!(node, self::parse::NodeTokens::Doctype { .. }))
-        {
-            // removing existing doctype declaration to insert our own later
-            // as a part of static string literal (for performance optimization)
-            nodes.remove(0);
+        Target::Server => expand_server(directives, nodes, emits_style),
+    }
+}
+
+fn expand_server(
+    directives: Vec<parse::Directive>,
+    mut nodes: Vec<parse::NodeTokens>,
+    emits_style: bool,
+) -> syn::Result<TokenStream> {
+    if nodes
+        .first()
+        .is_some_and(|node| matches!(node, self::parse::NodeTokens::Doctype { .. }))
+    {
+        // removing existing doctype declaration to insert our own later
+        // as a part of static string literal (for performance optimization)
+        nodes.remove(0);
+    }
+
+    let style = match style::stylesheet(&directives)? {
+        Some((scope, css)) if emits_style => {
+            // hoisted into `<head>` and deduplicated by `uibeam::Head`
+            let style_start = format!("<style data-uibeam-scope=\"{scope}\"");
+            let style_rest = format!(">{css}</style>");
+            Some(quote! {
+                <::uibeam::Head as ::uibeam::Beam>::render(::uibeam::Head {
+                    children: unsafe {::uibeam::UI::new_unchecked(
+                        &[#style_start, #style_rest],
+                        [::uibeam::Interpolator::Nonce],
+                    )},
+                }),
+            })
         }
+        _ => None,
+    };
 
-        let uis = nodes
-            .into_iter()
-            .map(|node| {
-                let is_html_tag = node.children_of_enclosing_tag("html").is_some();
+    let templates = nodes
+        .into_iter()
+        .map(|node| {
+            let is_html_tag = node.children_of_enclosing_tag("html").is_some();
 
-                let (mut literals, expressions, ehannotations) =
-                    transform::server::transform(&directives, node)?;
+            let (mut literals, expressions, ehannotations) =
+                transform::server::transform(&directives, node)?;
 
-                if is_html_tag {
-                    literals
-                        .first_mut()
-                        .unwrap()
-                        .edit(|lit| *lit = format!("<!DOCTYPE html>{lit}"));
-                }
+            if is_html_tag {
+                literals
+                    .first_mut()
+                    .unwrap()
+                    .edit(|lit| *lit = format!("<!DOCTYPE html>{lit}"));
+            }
 
-                let ehannotations = (!ehannotations.is_empty()).then(|| {
-                    quote! {
-                        if false {
-                            #(#ehannotations)*
-                        }
-                    }
-                });
-
-                Ok(quote! {{
-                    #ehannotations
-                    unsafe {::uibeam::UI::new_unchecked(
-                        &[#(#literals),*],
-                        [#(#expressions),*]
-                    )}
-                }})
-            })
-            .collect::<syn::Result<Vec<_>>>()?;
+            Ok((literals, expressions, ehannotations))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
 
-        Ok(quote! {
-            <::uibeam::UI>::concat([#(#uis),*])
+    if style.is_none()
+        && templates.iter().all(|(_, expressions, ehannotations)| {
+            expressions.is_empty() && ehannotations.is_empty()
         })
+    {
+        // fully static: just one `&'static str` as a `const`
+        let html = templates
+            .iter()
+            .flat_map(|(literals, _, _)| literals.iter().map(transform::server::Piece::as_str))
+            .collect::<String>();
+        return Ok(quote! {{
+            const STATIC: ::uibeam::UI = unsafe {::uibeam::UI::new_static(#html)};
+            STATIC
+        }});
     }
+
+    let uis = templates
+        .into_iter()
+        .map(|(literals, expressions, ehannotations)| {
+            let ehannotations = (!ehannotations.is_empty()).then(|| {
+                quote! {
+                    if false {
+                        #(#ehannotations)*
+                    }
+                }
+            });
+
+            // computed here after the doctype is inserted
+            let static_len = literals
+                .iter()
+                .map(transform::server::Piece::len)
+                .sum::<usize>();
+
+            quote! {{
+                #ehannotations
+                unsafe {::uibeam::UI::new_unchecked_sized(
+                    #static_len,
+                    &[#(#literals),*],
+                    [#(#expressions),*]
+                )}
+            }}
+        });
+
+    Ok(quote! {
+        <::uibeam::UI>::concat([#style #(#uis),*])
+    })
 }
@@ -5,7 +5,7 @@ use syn::{Expr, Ident, LitInt, LitStr, Token, token};
 
 /// Parsed representation of the UI macro input.
 ///
-/// This is almost HTML syntax, but with optional `@directive;`s and some Rust expressions embedded within `{}`.
+/// This is almost HTML syntax, but with optional `@directive;`s (or `@directive "value";`s) and some Rust expressions embedded within `{}`.
 pub(super) struct UITokens {
     pub(super) directives: Vec<Directive>,
     pub(super) nodes: Vec<NodeTokens>,
@@ -14,6 +14,7 @@ pub(super) struct UITokens {
 pub(super) struct Directive {
     pub(super) _at: Token![@],
     pub(super) name: Ident,
+    pub(super) value: Option<LitStr>,
     pub(super) _semi: Token![;],
 }
 impl Directive {
@@ -21,11 +22,39 @@ impl Directive {
         self.name == "client"
     }
 
+    /// CSS of `@style "...";`
+    pub(super) fn style(&self) -> syn::Result<Option<&LitStr>> {
+        if self.name != "style" {
+            return Ok(None);
+        }
+        self.value.as_ref().map(Some).ok_or_else(|| {
+            syn::Error::new(
+                self.name.span(),
+                "expected a string literal of CSS: `@style \"...\";`",
+            )
+        })
+    }
+
+    /// whether `@minify;`
+    pub(super) fn minify(&self) -> syn::Result<bool> {
+        if self.name != "minify" {
+            return Ok(false);
+        }
+        match &self.value {
+            None => Ok(true),
+            Some(value) => Err(syn::Error::new(
+                value.span(),
+                "`@minify` takes no value: `@minify;`",
+            )),
+        }
+    }
+
     #[allow(unused)]
     pub(super) fn new(name: &str) -> Self {
         Directive {
             _at: Default::default(),
             name: quote::format_ident!("{name}"),
+            value: None,
             _semi: Default::default(),
         }
     }
@@ -177,8 +206,14 @@ impl Parse for Directive {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let _at: Token![@] = input.parse()?;
         let name: Ident = input.parse()?;
+        let value: Option<LitStr> = input.peek(LitStr).then(|| input.parse()).transpose()?;
         let _semi: Token![;] = input.parse()?;
-        Ok(Directive { _at, name, _semi })
+        Ok(Directive {
+            _at,
+            name,
+            value,
+            _semi,
+        })
     }
 }
 
@@ -295,64 +330,18 @@ impl Parse for NodeTokens {
 
         #[cfg(feature = "client")]
         fn inject_hydration_hooks(node: &mut NodeTokens) {
-            // this hash is fixed for uibeam's (name, version) pair
-            //
-            // ref:
-            // - https://github.com/wasm-bindgen/wasm-bindgen/blob/184509116115e23517be1cb0bccff02672acde5b/crates/macro-support/src/encode.rs#L111
-            // - https://github.com/wasm-bindgen/wasm-bindgen/blob/184509116115e23517be1cb0bccff02672acde5b/crates/macro-support/src/hash.rs
-            fn wasm_bindgen_unique_crate_identifier(
-                name: impl AsRef<str>,
-                version: impl AsRef<str>,
-            ) -> String {
-                use std::hash::{Hash, Hasher};
-
-                let mut h = std::hash::DefaultHasher::new();
-                name.as_ref().hash(&mut h);
-                version.as_ref().hash(&mut h);
-                let hash = h.finish();
-
-                let mut h = std::hash::DefaultHasher::new();
-                hash.hash(&mut h);
-                0_i32.hash(&mut h);
-                let hash = h.finish();
-
-                format!("{hash:016x}")
-            }
-
-            static UIBEAM_HASH: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| {
-                // `uibeam_macros`'s version equals to `uibeam`'s version
-                wasm_bindgen_unique_crate_identifier("uibeam", env!("CARGO_PKG_VERSION"))
-            });
-            /*
-                TODO: generate and handle `{cratename}-{version}.(js|wasm)`
-                Currently fix their identifier to a concrete name
-                for references to it in the `uibeam/runtime/{runtime.js, bundle.sh}`.
-
-                static USER_ISLANDS_CRATE_NAME_VERSION: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| {
-                    let name = std::env::var("CARGO_PKG_NAME").unwrap();
-                    let version = std::env::var("CARGO_PKG_VERSION").unwrap();
-                    format!("{name}-{version}")
-                });
-            */
-            let runtime_mjs_path = format!("/.uibeam/snippets/uibeam-{}/runtime.mjs", *UIBEAM_HASH);
-            let hydrate_js_path = "/.uibeam/hydrate.js";
-            let hydrate_bg_wasm_path = "/.uibeam/hydrate_bg.wasm";
+            // assets of the islands crates are resolved at render time
+            // by the base URL and the crates registered in `uibeam::assets`
 
             if let Some(head_children) = node.children_of_enclosing_tag_mut("head") {
-                head_children.push(ContentPieceTokens::Node(syn::parse_quote! {
-                    <link rel="modulepreload" href=#runtime_mjs_path />
-                }));
-                head_children.push(ContentPieceTokens::Node(syn::parse_quote! {
-                    <link rel="modulepreload" href=#hydrate_js_path />
-                }));
-                head_children.push(ContentPieceTokens::Node(syn::parse_quote! {
-                    <link rel="prefetch" href=#hydrate_bg_wasm_path as="fetch" type="application/wasm" crossorigin>
+                head_children.push(ContentPieceTokens::Interpolation(syn::parse_quote! {
+                    {::uibeam::assets::__head_hooks__()}
                 }));
             }
 
             if let Some(body_children) = node.children_of_enclosing_tag_mut("body") {
-                body_children.push(ContentPieceTokens::Node(syn::parse_quote! {
-                    <script type="module" src=#hydrate_js_path></script>
+                body_children.push(ContentPieceTokens::Interpolation(syn::parse_quote! {
+                    {::uibeam::assets::__body_hooks__()}
                 }));
             }
         }
@@ -441,6 +430,7 @@ impl ToTokens for Directive {
     fn to_tokens(&self, t: &mut proc_macro2::TokenStream) {
         self._at.to_tokens(t);
         self.name.to_tokens(t);
+        self.value.to_tokens(t);
         self._semi.to_tokens(t);
     }
 }
@@ -0,0 +1,263 @@
+use super::parse::Directive;
+
+/// Collects `@style "...";` directives into `(scope class, scoped CSS)`.
+pub(super) fn stylesheet(directives: &[Directive]) -> syn::Result<Option<(String, String)>> {
+    Ok(collect_css(directives)?.map(|css| {
+        let scope = scope_of(&css);
+        let scoped = scope_css(&css, &scope);
+        (scope, scoped)
+    }))
+}
+
+/// Scope class to be added to every element in `UI!` with `@style` directives.
+pub(super) fn scope(directives: &[Directive]) -> syn::Result<Option<String>> {
+    Ok(collect_css(directives)?.map(|css| scope_of(&css)))
+}
+
+fn collect_css(directives: &[Directive]) -> syn::Result<Option<String>> {
+    let mut css = String::new();
+    for d in directives {
+        if let Some(lit) = d.style()? {
+            let value = lit.value();
+            if value.to_ascii_lowercase().contains("</style") {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "`</style` is not allowed in `@style` CSS",
+                ));
+            }
+            css.push_str(&value);
+            css.push('\n');
+        }
+    }
+    Ok((!css.is_empty()).then_some(css))
+}
+
+/// `ub-{hash of the CSS}`
+///
+/// FNV-1a is used to get the same class name between
+/// the server build and the Wasm build.
+fn scope_of(css: &str) -> String {
+    let mut hash: u32 = 0x811c9dc5;
+    for b in css.bytes() {
+        hash ^= b as u32;
+        hash = hash.wrapping_mul(0x01000193);
+    }
+    format!("ub-{hash:08x}")
+}
+
+/// Rewrites every selector in `css` to match only elements having `scope` class,
+/// e.g. `.card > p::before` to `.card > p.{scope}::before`.
+fn scope_css(css: &str, scope: &str) -> String {
+    let mut scoped = String::with_capacity(css.len() * 2);
+    scope_rules(css, scope, &mut scoped);
+    scoped
+}
+
+fn scope_rules(mut css: &str, scope: &str, out: &mut String) {
+    loop {
+        css = skip_spaces_and_comments(css);
+        if css.is_empty() {
+            return;
+        }
+
+        let Some(open) = find_top_level(css, |c| c == '{' || c == ';') else {
+            out.push_str(css.trim());
+            return;
+        };
+        if css.as_bytes()[open] == b';' {
+            // statement at-rule like `@import ...;`
+            out.push_str(css[..=open].trim());
+            css = &css[open + 1..];
+            continue;
+        }
+
+        let prelude = css[..open].trim();
+        let close = find_block_end(css, open);
+        let block = &css[open + 1..close];
+
+        if let Some(at_rule) = prelude.strip_prefix('@') {
+            out.push_str(prelude);
+            out.push('{');
+            let name = at_rule
+                .split(|c: char| c.is_ascii_whitespace() || c == '(')
+                .next()
+                .unwrap_or_default()
+                .to_ascii_lowercase();
+            if matches!(&*name, "media" | "supports" | "container" | "layer") {
+                scope_rules(block, scope, out);
+            } else {
+                // `@keyframes`, `@font-face`, ...
+                out.push_str(block.trim());
+            }
+            out.push('}');
+        } else {
+            for (i, selector) in split_top_level(prelude, ',').into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                scope_selector(selector.trim(), scope, out);
+            }
+            out.push('{');
+            out.push_str(block.trim());
+            out.push('}');
+        }
+
+        css = css.get(close + 1..).unwrap_or_default();
+    }
+}
+
+fn scope_selector(selector: &str, scope: &str, out: &mut String) {
+    let mut compound_start = 0;
+    for_each_top_level(selector, |i, c| {
+        if c.is_ascii_whitespace() || matches!(c, '>' | '+' | '~') {
+            compound_start = i + c.len_utf8();
+        }
+    });
+
+    let compound = &selector[compound_start..];
+    let mut insert_at = compound.len();
+    for_each_top_level(compound, |i, c| {
+        if c == ':' && insert_at == compound.len() {
+            let pseudo = compound[i..].to_ascii_lowercase();
+            if pseudo.starts_with("::")
+                || [":before", ":after", ":first-line", ":first-letter"]
+                    .iter()
+                    .any(|p| pseudo.starts_with(p))
+            {
+                insert_at = i;
+            }
+        }
+    });
+
+    out.push_str(&selector[..compound_start + insert_at]);
+    out.push('.');
+    out.push_str(scope);
+    out.push_str(&compound[insert_at..]);
+}
+
+fn skip_spaces_and_comments(mut css: &str) -> &str {
+    loop {
+        css = css.trim_start();
+        match css.strip_prefix("/*") {
+            Some(comment) => css = comment.find("*/").map_or("", |i| &comment[i + 2..]),
+            None => return css,
+        }
+    }
+}
+
+/// Calls `f` with each character out of strings, comments, `(...)` and `[...]`.
+fn for_each_top_level(s: &str, mut f: impl FnMut(usize, char)) {
+    let (mut depth, mut quote, mut in_comment) = (0_usize, None, false);
+    let mut chars = s.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if in_comment {
+            if c == '*' && chars.peek().is_some_and(|(_, c)| *c == '/') {
+                chars.next();
+                in_comment = false;
+            }
+            continue;
+        }
+        match (quote, c) {
+            (Some(_), '\\') => {
+                chars.next();
+            }
+            (Some(q), _) if q == c => quote = None,
+            (Some(_), _) => (),
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '/') if chars.peek().is_some_and(|(_, c)| *c == '*') => {
+                chars.next();
+                in_comment = true;
+            }
+            (None, '(' | '[') => depth += 1,
+            (None, ')' | ']') => depth = depth.saturating_sub(1),
+            (None, _) if depth == 0 => f(i, c),
+            (None, _) => (),
+        }
+    }
+}
+
+fn find_top_level(s: &str, pred: impl Fn(char) -> bool) -> Option<usize> {
+    let mut found = None;
+    for_each_top_level(s, |i, c| {
+        if found.is_none() && pred(c) {
+            found = Some(i);
+        }
+    });
+    found
+}
+
+fn split_top_level(s: &str, delimiter: char) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    for_each_top_level(s, |i, c| {
+        if c == delimiter {
+            pieces.push(&s[start..i]);
+            start = i + c.len_utf8();
+        }
+    });
+    pieces.push(&s[start..]);
+    pieces
+}
+
+/// returns the index of `}` corresponded to the `{` at `open`
+fn find_block_end(s: &str, open: usize) -> usize {
+    let mut depth = 0_usize;
+    let mut close = None;
+    for_each_top_level(&s[open..], |i, c| {
+        if close.is_some() {
+            return;
+        }
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    close = Some(open + i);
+                }
+            }
+            _ => (),
+        }
+    });
+    close.unwrap_or(s.len())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scope_css() {
+        for (css, expected) in [
+            (".card { padding: 1rem; }", ".card.ub-x{padding: 1rem;}"),
+            (
+                ".card > p, a:hover, li::before { color: red }",
+                ".card > p.ub-x,a:hover.ub-x,li.ub-x::before{color: red}",
+            ),
+            (
+                "/* comment */ input[type=\"a b\"] + label:after { content: \"{\"; }",
+                "input[type=\"a b\"] + label.ub-x:after{content: \"{\";}",
+            ),
+            (
+                "@media (max-width: 600px) { .card, .list { margin: 0 } }",
+                "@media (max-width: 600px){.card.ub-x,.list.ub-x{margin: 0}}",
+            ),
+            (
+                "@keyframes spin { from { rotate: 0deg } to { rotate: 360deg } } @import url(x.css);",
+                "@keyframes spin{from { rotate: 0deg } to { rotate: 360deg }}@import url(x.css);",
+            ),
+            (
+                ":is(h1, h2) span { color: blue }",
+                ":is(h1, h2) span.ub-x{color: blue}",
+            ),
+        ] {
+            assert_eq!(scope_css(css, "ub-x"), expected);
+        }
+    }
+
+    #[test]
+    fn test_scope_of() {
+        assert_eq!(scope_of(".a{}"), scope_of(".a{}"));
+        assert_ne!(scope_of(".a{}"), scope_of(".b{}"));
+        assert_eq!(scope_of("").len(), "ub-".len() + 8);
+    }
+}
@@ -52,7 +52,11 @@ impl NodeTokens {
 }
 
 impl Component<'_> {
-    fn into_rendering_expr_with(self, directives: &[Directive]) -> syn::Result<syn::Expr> {
+    fn into_rendering_expr_with(
+        self,
+        directives: &[Directive],
+        target: super::Target,
+    ) -> syn::Result<syn::Expr> {
         let Component {
             name,
             attributes,
@@ -99,13 +103,16 @@ impl Component<'_> {
                     .iter()
                     .map(ToTokens::to_token_stream)
                     .collect::<TokenStream>();
-                // Explicitly using `expand()`, instead of just returning
+                // Explicitly using `expand_children()`, instead of just returning
                 // `children: UI! { #(#directives)* #children_tokens }`,
                 // to avoid recursive macro expansions.
-                let children_tokens = crate::ui::expand(quote![
-                    #(#directives)*
-                    #children_tokens
-                ])?;
+                let children_tokens = crate::ui::expand_children(
+                    quote![
+                        #(#directives)*
+                        #children_tokens
+                    ],
+                    target,
+                )?;
                 quote! {
                     children: #children_tokens,
                 }
@@ -127,6 +134,21 @@ impl Component<'_> {
     }
 }
 
+/// `::uibeam::AttributeContext` of the attribute `name`, resolved at compile time
+fn attribute_context(name: &str) -> TokenStream {
+    use uibeam_html::AttributeContext;
+
+    let variant = match AttributeContext::of(name) {
+        AttributeContext::Text => quote! { Text },
+        AttributeContext::Url => quote! { Url },
+        AttributeContext::UrlList => quote! { UrlList },
+        AttributeContext::Css => quote! { Css },
+        AttributeContext::Html => quote! { Html },
+        AttributeContext::Js => quote! { Js },
+    };
+    quote! { ::uibeam::AttributeContext::#variant }
+}
+
 fn prop_for_event(event: &str) -> syn::Result<(Ident, Type)> {
     macro_rules! preact_handlers {
         ($($eventname:literal: $propName:ident($Event:ty);)*) => {
@@ -1,17 +1,18 @@
 #![cfg(feature = "client")]
 
+use super::super::Target;
 use super::super::parse::{
-    AttributeTokens, AttributeValueToken, AttributeValueTokens, ContentPieceTokens,
+    AttributeTokens, AttributeValueToken, AttributeValueTokens, ContentPieceTokens, Directive,
     InterpolationTokens, NodeTokens,
 };
-use super::prop_for_event;
+use super::{attribute_context, prop_for_event};
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 use syn::{Expr, LitStr};
 
 fn as_event_handler(name: &str, expression: &Expr) -> Option<syn::Result<(LitStr, TokenStream)>> {
-    name.strip_prefix("on").map(|event| {
-        prop_for_event(&event.to_ascii_lowercase()).map(|(prop, event)| {
+    (uibeam_html::AttributeContext::of(name) == uibeam_html::AttributeContext::Js).then(|| {
+        prop_for_event(&name[2..].to_ascii_lowercase()).map(|(prop, event)| {
             (
                 LitStr::new(&prop.to_string(), prop.span()),
                 quote! {
@@ -26,18 +27,53 @@ fn as_event_handler(name: &str, expression: &Expr) -> Option<syn::Result<(LitStr
 
 /// Derives Rust codes that builds an `uibeam::client::VNode` expression
 /// corresponded to the `UI!` input
-pub(crate) fn transform(tokens: NodeTokens) -> syn::Result<TokenStream> {
-    fn into_props(attributes: Vec<AttributeTokens>, is_beam: bool) -> syn::Result<TokenStream> {
-        if attributes.is_empty() {
+///
+/// `directives` are expected to include `@client`.
+pub(crate) fn transform(directives: &[Directive], tokens: NodeTokens) -> syn::Result<TokenStream> {
+    fn into_props(
+        scope: Option<&str>,
+        attributes: Vec<AttributeTokens>,
+        is_beam: bool,
+    ) -> syn::Result<TokenStream> {
+        let has_class = attributes.iter().any(|a| a.name.to_string() == "class");
+
+        if attributes.is_empty() && scope.is_none() {
             return Ok(quote! {
                 ::uibeam::client::js_sys::Object::new()
             });
         }
 
-        let kvs = attributes
+        let mut kvs = attributes
             .into_iter()
             .map(|AttributeTokens { name, value }| {
                 let name = name.to_string();
+                if let (Some(scope), "class") = (scope, &*name) {
+                    return Ok(match value.map(|v| v.value) {
+                        None => quote! {
+                            (#name, ::uibeam::client::wasm_bindgen::JsValue::from(#scope))
+                        },
+                        Some(AttributeValueToken::StringLiteral(s)) => {
+                            let s = format!("{} {scope}", uibeam_html::escape(&s.value()));
+                            quote! {
+                                (#name, ::uibeam::client::wasm_bindgen::JsValue::from(#s))
+                            }
+                        }
+                        Some(AttributeValueToken::IntegerLiteral(i)) => {
+                            let s = format!("{} {scope}", i.base10_digits());
+                            quote! {
+                                (#name, ::uibeam::client::wasm_bindgen::JsValue::from(#s))
+                            }
+                        }
+                        Some(AttributeValueToken::Interpolation(InterpolationTokens {
+                            rust_expression,
+                            ..
+                        })) => quote! {
+                            (#name, ::uibeam::client::wasm_bindgen::JsValue::from(
+                                ::uibeam::AttributeValue::from(#rust_expression).with_class(#scope)
+                            ))
+                        },
+                    });
+                }
                 match value {
                     None => Ok(quote! {
                         (#name, ::uibeam::client::wasm_bindgen::JsValue::TRUE)
@@ -63,6 +99,17 @@ pub(crate) fn transform(tokens: NodeTokens) -> syn::Result<TokenStream> {
                                     (#prop, #event_handler)
                                 })
                             }
+                            (false, None)
+                                if uibeam_html::AttributeContext::of(&name)
+                                    != uibeam_html::AttributeContext::Text =>
+                            {
+                                let context = attribute_context(&name);
+                                Ok(quote! {
+                                    (#name, ::uibeam::client::wasm_bindgen::JsValue::from(
+                                        ::uibeam::AttributeValue::from(#rust_expression).sanitize(#context)
+                                    ))
+                                })
+                            }
                             _ => Ok(quote! {
                                 (#name, ::uibeam::client::wasm_bindgen::JsValue::from(
                                     ::uibeam::AttributeValue::from(#rust_expression)
@@ -74,6 +121,12 @@ pub(crate) fn transform(tokens: NodeTokens) -> syn::Result<TokenStream> {
             })
             .collect::<syn::Result<Vec<_>>>()?;
 
+        if let (Some(scope), false) = (scope, has_class) {
+            kvs.push(quote! {
+                ("class", ::uibeam::client::wasm_bindgen::JsValue::from(#scope))
+            });
+        }
+
         Ok(quote! {
             {
                 let props = ::uibeam::client::js_sys::Object::new();
@@ -85,7 +138,10 @@ pub(crate) fn transform(tokens: NodeTokens) -> syn::Result<TokenStream> {
         })
     }
 
-    fn into_children(content: Vec<ContentPieceTokens>) -> syn::Result<TokenStream> {
+    fn into_children(
+        directives: &[Directive],
+        content: Vec<ContentPieceTokens>,
+    ) -> syn::Result<TokenStream> {
         let children = content
             .into_iter()
             .map(|piece| match piece {
@@ -106,12 +162,13 @@ pub(crate) fn transform(tokens: NodeTokens) -> syn::Result<TokenStream> {
                 }) => {
                     let is_escape = syn::LitBool::new(_unsafe.is_none(), Span::call_site());
                     Ok(quote! {
-                        ::uibeam::IntoChildren::<_, #is_escape>::into_children(
-                            #rust_expression
-                        ).into_vdom()
+                        {
+                            use ::uibeam::IntoChildrenFallback as _;
+                            ::uibeam::Children::<_, #is_escape>(#rust_expression).into_children()
+                        }.into_vdom()
                     })
                 }
-                ContentPieceTokens::Node(n) => transform(n),
+                ContentPieceTokens::Node(n) => transform(directives, n),
             })
             .collect::<syn::Result<Vec<_>>>()?;
 
@@ -120,10 +177,16 @@ pub(crate) fn transform(tokens: NodeTokens) -> syn::Result<TokenStream> {
         })
     }
 
-    fn encode(t: &mut TokenStream, tokens: NodeTokens) -> syn::Result<()> {
+    fn encode(
+        t: &mut TokenStream,
+        directives: &[Directive],
+        tokens: NodeTokens,
+    ) -> syn::Result<()> {
+        let scope = crate::ui::style::scope(directives)?;
+        let scope = scope.as_deref();
+
         if let Some(beam) = tokens.as_beam() {
-            let rendering_expr =
-                beam.into_rendering_expr_with(&[super::super::parse::Directive::new("client")])?;
+            let rendering_expr = beam.into_rendering_expr_with(directives, Target::Hydrate)?;
             (quote! {
                 #rendering_expr.into_vdom()
             })
@@ -145,9 +208,9 @@ pub(crate) fn transform(tokens: NodeTokens) -> syn::Result<TokenStream> {
                 } => {
                     let tag = tag.to_string();
 
-                    let props = into_props(attributes, false)?;
+                    let props = into_props(scope, attributes, false)?;
 
-                    let children = into_children(content)?;
+                    let children = into_children(directives, content)?;
 
                     (quote! {
                         ::uibeam::client::VNode::new(
@@ -168,7 +231,7 @@ pub(crate) fn transform(tokens: NodeTokens) -> syn::Result<TokenStream> {
                 } => {
                     let tag = tag.to_string();
 
-                    let props = into_props(attributes, false)?;
+                    let props = into_props(scope, attributes, false)?;
 
                     (quote! {
                         ::uibeam::client::VNode::new(
@@ -181,7 +244,7 @@ pub(crate) fn transform(tokens: NodeTokens) -> syn::Result<TokenStream> {
                 }
 
                 NodeTokens::TextNode(node_pieces) => {
-                    let vnodes_vec = into_children(node_pieces)?;
+                    let vnodes_vec = into_children(directives, node_pieces)?;
                     (quote! {
                         ::uibeam::client::VNode::fragment(#vnodes_vec)
                     })
@@ -193,6 +256,6 @@ pub(crate) fn transform(tokens: NodeTokens) -> syn::Result<TokenStream> {
     }
 
     let mut t = TokenStream::new();
-    encode(&mut t, tokens)?;
+    encode(&mut t, directives, tokens)?;
     Ok(t)
 }
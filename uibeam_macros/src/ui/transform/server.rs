@@ -1,8 +1,9 @@
+use super::super::Target;
 use super::super::parse::{
-    AttributeTokens, AttributeValueToken, ContentPieceTokens, Directive, InterpolationTokens,
-    NodeTokens,
+    AttributeTokens, AttributeValueToken, AttributeValueTokens, ContentPieceTokens, Directive,
+    InterpolationTokens, NodeTokens,
 };
-use super::prop_for_event;
+use super::{attribute_context, prop_for_event};
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 use syn::{Expr, ExprLit, Lit, LitStr, Type, spanned::Spanned};
@@ -49,33 +50,49 @@ impl Piece {
         }
     }
 
+    pub(crate) fn len(&self) -> usize {
+        self.0.as_ref().map_or(0, String::len)
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        self.0.as_deref().unwrap_or_default()
+    }
+
     pub(crate) fn edit(&mut self, f: impl FnOnce(&mut String)) {
         self.0.as_mut().map(f);
     }
 }
 
 pub(crate) enum Interpolation {
-    Attribute(Expr),
+    /// `::uibeam::AttributeContext` of the attribute and the value
+    Attribute(TokenStream, Expr),
     Children(Expr),
     UnsafeRawChildren(Expr),
+    /// slot for CSP `nonce` in a start tag
+    Nonce,
 }
 impl ToTokens for Interpolation {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match self {
-            Interpolation::Attribute(expression) => tokens.extend(quote! {
-                ::uibeam::Interpolator::Attribute(::uibeam::AttributeValue::from(
+            Interpolation::Attribute(context, expression) => tokens.extend(quote! {
+                ::uibeam::Interpolator::Attribute(#context, ::uibeam::AttributeValue::from(
                     #expression
                 ))
             }),
             Interpolation::Children(expression) => tokens.extend(quote! {
-                ::uibeam::Interpolator::Children(::uibeam::IntoChildren::<_, true>::into_children(
-                    #expression
-                ))
+                ::uibeam::Interpolator::Children({
+                    use ::uibeam::IntoChildrenFallback as _;
+                    ::uibeam::Children::<_, true>(#expression).into_children()
+                })
+            }),
+            Interpolation::Nonce => tokens.extend(quote! {
+                ::uibeam::Interpolator::Nonce
             }),
             Interpolation::UnsafeRawChildren(expression) => tokens.extend(quote! {
-                ::uibeam::Interpolator::Children(::uibeam::IntoChildren::<_, false>::into_children(
-                    #expression
-                ))
+                ::uibeam::Interpolator::Children({
+                    use ::uibeam::IntoChildrenFallback as _;
+                    ::uibeam::Children::<_, false>(#expression).into_children()
+                })
             }),
         }
     }
@@ -101,6 +118,33 @@ impl ToTokens for EventHandlerAnnotation {
     }
 }
 
+/// whether the start tag gets a slot for CSP `nonce`: `<script>`, `<style>`, or
+/// `<link>` loading a script or a stylesheet (or of a `rel` given at runtime)
+fn needs_nonce(tag: &str, attributes: &[AttributeTokens]) -> bool {
+    let attribute = |name: &str| attributes.iter().find(|a| a.name.to_string() == name);
+    if attribute("nonce").is_some() {
+        return false;
+    }
+    match &*tag.to_ascii_lowercase() {
+        "script" | "style" => true,
+        "link" => match attribute("rel")
+            .and_then(|a| a.value.as_ref())
+            .map(|v| &v.value)
+        {
+            Some(AttributeValueToken::StringLiteral(rel)) => {
+                rel.value().split_ascii_whitespace().any(|r| {
+                    ["stylesheet", "preload", "modulepreload"]
+                        .iter()
+                        .any(|s| s.eq_ignore_ascii_case(r))
+                })
+            }
+            Some(AttributeValueToken::Interpolation(_)) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
 /// Derives `({HTML-escaped literal pieces}, {interpolating expressions})`
 /// from the `NodeTokens`
 pub(crate) fn transform(
@@ -146,8 +190,12 @@ pub(crate) fn transform(
         interpolations: &mut Vec<Interpolation>,
         ehannotations: &mut Vec<EventHandlerAnnotation>,
     ) -> syn::Result<()> {
+        let scope = crate::ui::style::scope(directives)?;
+        let has_class = attributes.iter().any(|a| a.name.to_string() == "class");
+
         for AttributeTokens { name, value } in attributes {
-            if let Some(event) = name.to_string().strip_prefix("on") {
+            let name_str = name.to_string();
+            if uibeam_html::AttributeContext::of(&name_str) == uibeam_html::AttributeContext::Js {
                 if !directives.iter().any(|d| d.client()) {
                     return Err(syn::Error::new(
                         name.span(),
@@ -157,14 +205,57 @@ pub(crate) fn transform(
                         ),
                     ));
                 }
-                let (_prop, event_type) = prop_for_event(&event.to_ascii_lowercase())?;
+                let Some(AttributeValueTokens {
+                    value: AttributeValueToken::Interpolation(handler),
+                    ..
+                }) = value
+                else {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        format!(
+                            "`{name}` only accepts an event handler like `{name}={{|e| ...}}`; \
+                            inline scripts are not allowed"
+                        ),
+                    ));
+                };
+                let (_prop, event_type) = prop_for_event(&name_str[2..].to_ascii_lowercase())?;
                 ehannotations.push(EventHandlerAnnotation {
-                    handler_expression: syn::parse2(value.unwrap().value.into_token_stream())?,
+                    handler_expression: handler.rust_expression,
                     event_type,
                 });
                 continue;
             }
 
+            if let (Some(scope), true) = (&scope, name.to_string() == "class") {
+                current_piece.join(Piece::new(" class="));
+                match value.map(|v| v.value) {
+                    None => current_piece.join(Piece::new(format!("\"{scope}\""))),
+                    Some(AttributeValueToken::StringLiteral(lit)) => {
+                        current_piece.join(Piece::new(format!(
+                            "\"{} {scope}\"",
+                            uibeam_html::escape(&lit.value())
+                        )));
+                    }
+                    Some(AttributeValueToken::IntegerLiteral(lit)) => {
+                        current_piece
+                            .join(Piece::new(format!("\"{} {scope}\"", lit.base10_digits())));
+                    }
+                    Some(AttributeValueToken::Interpolation(InterpolationTokens {
+                        rust_expression,
+                        ..
+                    })) => {
+                        current_piece.commit(pieces);
+                        interpolations.push(Interpolation::Attribute(
+                            attribute_context("class"),
+                            syn::parse_quote! {
+                                ::uibeam::AttributeValue::from(#rust_expression).with_class(#scope)
+                            },
+                        ));
+                    }
+                }
+                continue;
+            }
+
             current_piece.join(Piece::new(format!(" {name}")));
             if let Some(value) = value {
                 current_piece.join(Piece::new("="));
@@ -184,11 +275,19 @@ pub(crate) fn transform(
                         ..
                     }) => {
                         current_piece.commit(pieces);
-                        interpolations.push(Interpolation::Attribute(rust_expression));
+                        interpolations.push(Interpolation::Attribute(
+                            attribute_context(&name_str),
+                            rust_expression,
+                        ));
                     }
                 }
             }
         }
+
+        if let (Some(scope), false) = (scope, has_class) {
+            current_piece.join(Piece::new(format!(" class=\"{scope}\"")));
+        }
+
         Ok(())
     }
 
@@ -272,7 +371,7 @@ pub(crate) fn transform(
         piece.join(Piece::new_empty());
         piece.commit(&mut pieces);
         interpolations.push(Interpolation::Children(
-            beam.into_rendering_expr_with(directives)?,
+            beam.into_rendering_expr_with(directives, Target::Server)?,
         ));
         piece.join(Piece::new_empty());
         piece.commit(&mut pieces);
@@ -303,6 +402,7 @@ pub(crate) fn transform(
                 _end_close,
             } => {
                 piece.join(Piece::new(format!("<{tag}")));
+                let needs_nonce = needs_nonce(&tag.to_string(), &attributes);
                 handle_attributes(
                     directives,
                     attributes,
@@ -311,6 +411,12 @@ pub(crate) fn transform(
                     &mut interpolations,
                     &mut ehannotations,
                 )?;
+                if needs_nonce {
+                    // may be just after an interpolated attribute value
+                    piece.is_none().then(|| piece = Piece::new_empty());
+                    piece.commit(&mut pieces);
+                    interpolations.push(Interpolation::Nonce);
+                }
                 piece.join(Piece::new(">"));
                 handle_content_pieces(
                     directives,
@@ -331,6 +437,7 @@ pub(crate) fn transform(
                 _end,
             } => {
                 piece.join(Piece::new(format!("<{tag}")));
+                let needs_nonce = needs_nonce(&tag.to_string(), &attributes);
                 handle_attributes(
                     directives,
                     attributes,
@@ -339,6 +446,12 @@ pub(crate) fn transform(
                     &mut interpolations,
                     &mut ehannotations,
                 )?;
+                if needs_nonce {
+                    // may be just after an interpolated attribute value
+                    piece.is_none().then(|| piece = Piece::new_empty());
+                    piece.commit(&mut pieces);
+                    interpolations.push(Interpolation::Nonce);
+                }
                 piece.join(Piece::new("/>"));
             }
 